@@ -0,0 +1,186 @@
+//! # Achievements
+//!
+//! Milestone badges unlocked from `ContributionStats` after each fetch.
+//! Unlocked achievements are stored per tracked user with their unlock
+//! date, so each one is announced exactly once, even across restarts
+//! and username switches.
+//!
+//! ## Milestones
+//!
+//! ```text
+//! Kind       Id                Unlocked when
+//! ──────────────────────────────────────────────────────────────
+//! streak     streak-7          longest streak ≥ 7 days
+//!            streak-30/100/365
+//! total      total-100         total contributions ≥ 100
+//!            total-1000/10000
+//! best-day   best-day-42       a new best day ever (42 contributions)
+//! ```
+//!
+//! The first fetch of a user only records their best day so far; a
+//! best-day achievement needs a later day that beats it.
+//!
+//! ## Unlock Flow
+//!
+//! ```text
+//! fetch ──▶ detect_achievements(stats, user's achievements)
+//!                    │
+//!                    ▼ newly unlocked
+//!           ┌──────────────────────────┐
+//!           │ Persist with unlock date │
+//!           └──────────────────────────┘
+//!                    │
+//!                    ▼
+//!           Notification + "achievement-unlocked" event
+//! ```
+
+use crate::types::{Achievement, AchievementKind, ContributionStats, UserAchievements};
+
+/// Streak milestones in days.
+pub const STREAK_MILESTONES: [u32; 4] = [7, 30, 100, 365];
+
+/// Contribution total milestones.
+const TOTAL_MILESTONES: [u32; 3] = [100, 1_000, 10_000];
+
+/// Returns the achievements unlocked by `stats` that aren't unlocked yet.
+///
+/// Streak milestones use the longest streak under the configured
+/// streak rules. A best-day record is unlocked whenever the best day
+/// beats the recorded best day; without a record (first fetch) nothing
+/// is unlocked, see `record_best_day`.
+///
+/// # Arguments
+///
+/// * `stats` - Freshly calculated statistics
+/// * `achievements` - The user's achievements so far
+/// * `now` - Unlock timestamp (ISO 8601)
+///
+/// # Returns
+///
+/// The newly unlocked achievements (empty if nothing changed)
+pub fn detect_achievements(
+    stats: &ContributionStats,
+    achievements: &UserAchievements,
+    now: &str,
+) -> Vec<Achievement> {
+    let is_unlocked = |id: &str| achievements.unlocked.iter().any(|a| a.id == id);
+    let mut new = Vec::new();
+
+    let longest = stats.relaxed_longest_streak.count;
+    for days in STREAK_MILESTONES.iter().filter(|days| longest >= **days) {
+        let id = format!("streak-{}", days);
+        if !is_unlocked(&id) {
+            new.push(Achievement {
+                id,
+                kind: AchievementKind::Streak,
+                title: format!("{}-day streak", days),
+                description: format!("Contributed {} days in a row", days),
+                value: *days,
+                unlocked_at: now.to_string(),
+            });
+        }
+    }
+
+    let total = stats.total_contributions;
+    for milestone in TOTAL_MILESTONES.iter().filter(|m| total >= **m) {
+        let id = format!("total-{}", milestone);
+        if !is_unlocked(&id) {
+            new.push(Achievement {
+                id,
+                kind: AchievementKind::Total,
+                title: format!("{} contributions", milestone),
+                description: format!("Reached {} contributions in a year", milestone),
+                value: *milestone,
+                unlocked_at: now.to_string(),
+            });
+        }
+    }
+
+    let beats_record = achievements
+        .best_day_record
+        .is_some_and(|record| stats.best_day.count > record);
+    if beats_record {
+        new.push(Achievement {
+            id: format!("best-day-{}", stats.best_day.count),
+            kind: AchievementKind::BestDay,
+            title: "Best day ever".to_string(),
+            description: format!(
+                "{} contributions on {}",
+                stats.best_day.count, stats.best_day.date
+            ),
+            value: stats.best_day.count,
+            unlocked_at: now.to_string(),
+        });
+    }
+
+    new
+}
+
+/// Records the best day seen so far, keeping the highest one.
+///
+/// The calendar only covers the last year, so the best day can drop
+/// out of the window; the record never decreases.
+///
+/// # Arguments
+///
+/// * `achievements` - The user's achievements to update
+/// * `stats` - Freshly calculated statistics
+///
+/// # Returns
+///
+/// `true` if the record changed (including the first, silent seed)
+pub fn record_best_day(achievements: &mut UserAchievements, stats: &ContributionStats) -> bool {
+    let record = achievements
+        .best_day_record
+        .unwrap_or(0)
+        .max(stats.best_day.count);
+    let changed = achievements.best_day_record != Some(record);
+    achievements.best_day_record = Some(record);
+    changed
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::calculate_stats;
+    use crate::test_support::days;
+    use crate::types::StreakRules;
+    use chrono::NaiveDate;
+
+    /// Tests that milestones unlock once and best-day records only when beaten.
+    #[test]
+    fn test_detect_achievements() {
+        let days = days("2024-01-01", &[3; 40]);
+        let stats = calculate_stats(
+            &days,
+            NaiveDate::from_ymd_opt(2024, 2, 9).unwrap(),
+            &StreakRules::default(),
+        );
+
+        // The first fetch unlocks milestones but only seeds the best day
+        let mut achievements = UserAchievements::default();
+        let first = detect_achievements(&stats, &achievements, "2024-02-09T12:00:00Z");
+        let ids: Vec<&str> = first.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, ["streak-7", "streak-30", "total-100"]);
+        achievements.unlocked.extend(first);
+        assert!(record_best_day(&mut achievements, &stats));
+        assert_eq!(achievements.best_day_record, Some(3));
+
+        // Nothing is announced twice
+        assert!(detect_achievements(&stats, &achievements, "2024-02-10T12:00:00Z").is_empty());
+        assert!(!record_best_day(&mut achievements, &stats));
+
+        // A later day that beats the record is announced
+        let mut better = stats.clone();
+        better.best_day.count = 5;
+        let ids: Vec<String> = detect_achievements(&better, &achievements, "2024-02-11T12:00:00Z")
+            .into_iter()
+            .map(|a| a.id)
+            .collect();
+        assert_eq!(ids, ["best-day-5"]);
+    }
+}
@@ -0,0 +1,65 @@
+//! # Clock Abstraction
+//!
+//! Statistics depend on what "today" is. Instead of reading the wall
+//! clock directly, the app asks a `Clock`, which lets tests (and the
+//! "as of" statistics API) use a fixed point in time.
+//!
+//! ```text
+//! ┌──────────────┐     now()      ┌─────────────────┐  today()  ┌────────────┐
+//! │ SystemClock  │───────────────▶│  UserTimezone   │──────────▶│ NaiveDate  │
+//! │ FixedClock   │   DateTime<Utc>│ (user's zone)   │           │ "today"    │
+//! └──────────────┘                └─────────────────┘           └────────────┘
+//! ```
+
+use crate::timezone::UserTimezone;
+use chrono::{DateTime, NaiveDate, Utc};
+
+/// A source of the current time.
+pub trait Clock: Send + Sync {
+    /// Returns the current instant.
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Returns today's date in the given timezone.
+    fn today(&self, timezone: &UserTimezone) -> NaiveDate {
+        timezone.date_of(self.now())
+    }
+}
+
+/// The real wall clock, used by the app.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock stopped at a fixed instant, used for replaying history and tests.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    /// Tests that a fixed clock reports "today" in the user's timezone.
+    #[test]
+    fn test_fixed_clock_today() {
+        let clock = FixedClock(Utc.with_ymd_and_hms(2024, 3, 10, 2, 0, 0).unwrap());
+        let los_angeles = UserTimezone::parse("America/Los_Angeles").unwrap();
+
+        assert_eq!(clock.today(&los_angeles).to_string(), "2024-03-09");
+    }
+}
@@ -0,0 +1,261 @@
+//! # Data Export
+//!
+//! Writes the cached contribution data to CSV or JSON so it can be
+//! analyzed elsewhere. Both formats carry the same content and a
+//! schema version.
+//!
+//! ## CSV Layout
+//!
+//! ```text
+//! schema_version,1                  ◀── Metadata (key,value)
+//! username,octocat
+//! ...
+//!                                   ◀── Blank line between sections
+//! date,count,level                  ◀── Days
+//! 2024-03-01,3,2
+//! ...
+//!
+//! stat,value                        ◀── Scalar statistics
+//! total_contributions,1234
+//! ...
+//!
+//! start_date,end_date,count,total_contributions   ◀── Streaks
+//! 2024-03-01,2024-03-03,3,6
+//! ```
+//!
+//! The JSON export is the pretty-printed `ContributionExport`, which
+//! also includes the monthly and weekday totals.
+//!
+//! Both are built from dedicated export types, so the schema only
+//! changes when this module changes.
+
+use crate::github::calculate_streak_history;
+use crate::types::{
+    ContributionDay, ContributionExport, ContributionStats, ExportDay, ExportFormat, ExportMonth,
+    ExportStats, ExportStreak, ExportWeekday, RangeStats, StreakRules,
+};
+use chrono::NaiveDate;
+
+/// Current export schema version.
+///
+/// Bump this when a field is renamed or removed; adding fields is fine.
+pub const EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Assembles the export from the statistics of a date range.
+///
+/// # Arguments
+///
+/// * `username` - The tracked GitHub login
+/// * `range` - Statistics and weeks for the exported range
+/// * `as_of` - Today's date in the user's timezone
+/// * `rules` - Streak rules used for the streak list
+/// * `exported_at` - Export timestamp (ISO 8601)
+///
+/// # Returns
+///
+/// The export with the current schema version
+pub fn build_export(
+    username: &str,
+    range: RangeStats,
+    as_of: NaiveDate,
+    rules: &StreakRules,
+    exported_at: &str,
+) -> ContributionExport {
+    let days: Vec<ContributionDay> = range.weeks.into_iter().flat_map(|week| week.days).collect();
+    let streaks = calculate_streak_history(&days, as_of, rules)
+        .streaks
+        .into_iter()
+        .map(|streak| ExportStreak {
+            start_date: streak.start_date,
+            end_date: streak.end_date,
+            count: streak.count,
+            total_contributions: streak.total_contributions,
+        })
+        .collect();
+
+    ContributionExport {
+        schema_version: EXPORT_SCHEMA_VERSION,
+        username: username.to_string(),
+        exported_at: exported_at.to_string(),
+        start_date: range.start_date,
+        end_date: range.end_date,
+        days: days
+            .into_iter()
+            .map(|day| ExportDay {
+                date: day.date,
+                count: day.count,
+                level: day.level,
+            })
+            .collect(),
+        stats: export_stats(&range.stats),
+        streaks,
+    }
+}
+
+/// Copies the exported fields out of the internal statistics.
+fn export_stats(stats: &ContributionStats) -> ExportStats {
+    ExportStats {
+        total_contributions: stats.total_contributions,
+        best_day_date: stats.best_day.date.clone(),
+        best_day_count: stats.best_day.count,
+        average_per_day: stats.average_per_day,
+        median_per_day: stats.median_per_day,
+        std_deviation: stats.std_deviation,
+        current_streak: stats.current_streak.count,
+        longest_streak: stats.longest_streak.count,
+        relaxed_current_streak: stats.relaxed_current_streak.count,
+        relaxed_longest_streak: stats.relaxed_longest_streak.count,
+        current_weekly_streak: stats.current_weekly_streak.count,
+        longest_weekly_streak: stats.longest_weekly_streak.count,
+        current_monthly_streak: stats.current_monthly_streak.count,
+        longest_monthly_streak: stats.longest_monthly_streak.count,
+        consistency_score: stats.consistency_score,
+        monthly_totals: stats
+            .monthly_totals
+            .iter()
+            .map(|month| ExportMonth {
+                month: month.month.clone(),
+                total: month.total,
+                active_days: month.active_days,
+            })
+            .collect(),
+        weekday_totals: stats
+            .weekday_distribution
+            .iter()
+            .map(|weekday| ExportWeekday {
+                weekday: weekday.weekday,
+                total: weekday.total,
+                mean: weekday.mean,
+            })
+            .collect(),
+    }
+}
+
+/// Renders the export in the requested format.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The file contents
+/// * `Err(String)` - Error message if JSON serialization fails
+pub fn render_export(export: &ContributionExport, format: ExportFormat) -> Result<String, String> {
+    match format {
+        ExportFormat::Csv => Ok(to_csv(export)),
+        ExportFormat::Json => serde_json::to_string_pretty(export)
+            .map_err(|e| format!("Failed to serialize export: {}", e)),
+    }
+}
+
+/// Renders the sectioned CSV described at the top of this file.
+fn to_csv(export: &ContributionExport) -> String {
+    let stats = &export.stats;
+    let mut lines: Vec<String> = vec![
+        format!("schema_version,{}", export.schema_version),
+        format!("username,{}", export.username),
+        format!("exported_at,{}", export.exported_at),
+        format!("start_date,{}", export.start_date),
+        format!("end_date,{}", export.end_date),
+        String::new(),
+        "date,count,level".to_string(),
+    ];
+
+    lines.extend(
+        export
+            .days
+            .iter()
+            .map(|day| format!("{},{},{}", day.date, day.count, day.level)),
+    );
+
+    lines.push(String::new());
+    lines.push("stat,value".to_string());
+    let scalars: [(&str, String); 15] = [
+        ("total_contributions", stats.total_contributions.to_string()),
+        ("best_day_date", stats.best_day_date.clone()),
+        ("best_day_count", stats.best_day_count.to_string()),
+        ("average_per_day", stats.average_per_day.to_string()),
+        ("median_per_day", stats.median_per_day.to_string()),
+        ("std_deviation", stats.std_deviation.to_string()),
+        ("current_streak", stats.current_streak.to_string()),
+        ("longest_streak", stats.longest_streak.to_string()),
+        (
+            "relaxed_current_streak",
+            stats.relaxed_current_streak.to_string(),
+        ),
+        (
+            "relaxed_longest_streak",
+            stats.relaxed_longest_streak.to_string(),
+        ),
+        (
+            "current_weekly_streak",
+            stats.current_weekly_streak.to_string(),
+        ),
+        (
+            "longest_weekly_streak",
+            stats.longest_weekly_streak.to_string(),
+        ),
+        (
+            "current_monthly_streak",
+            stats.current_monthly_streak.to_string(),
+        ),
+        (
+            "longest_monthly_streak",
+            stats.longest_monthly_streak.to_string(),
+        ),
+        ("consistency_score", stats.consistency_score.to_string()),
+    ];
+    lines.extend(
+        scalars
+            .iter()
+            .map(|(name, value)| format!("{},{}", name, value)),
+    );
+
+    lines.push(String::new());
+    lines.push("start_date,end_date,count,total_contributions".to_string());
+    lines.extend(export.streaks.iter().map(|streak| {
+        format!(
+            "{},{},{},{}",
+            streak.start_date, streak.end_date, streak.count, streak.total_contributions
+        )
+    }));
+
+    lines.join("\n") + "\n"
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::calculate_range_stats;
+    use crate::test_support::days;
+
+    /// Tests that both formats carry the schema version, days and streaks.
+    #[test]
+    fn test_render_export() {
+        let days = days("2024-03-01", &[2, 1, 0, 3]);
+        let start = NaiveDate::from_ymd_opt(2024, 3, 2).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 3, 4).unwrap();
+        let rules = StreakRules::default();
+
+        let range = calculate_range_stats(&days, start, end, end, &rules);
+        let export = build_export("octocat", range, end, &rules, "2024-03-04T12:00:00Z");
+        assert_eq!(export.days.len(), 3);
+
+        let csv = render_export(&export, ExportFormat::Csv).unwrap();
+        assert!(csv.starts_with("schema_version,1\nusername,octocat\n"));
+        assert!(csv.contains("\ndate,count,level\n2024-03-02,1,1\n"));
+        assert!(csv.contains("\ntotal_contributions,4\n"));
+        assert!(csv.ends_with("2024-03-04,2024-03-04,1,3\n"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render_export(&export, ExportFormat::Json).unwrap()).unwrap();
+        assert_eq!(json["schemaVersion"], 1);
+        assert_eq!(json["startDate"], "2024-03-02");
+        assert_eq!(json["streaks"][0]["count"], 1);
+        assert_eq!(json["stats"]["totalContributions"], 4);
+        assert_eq!(json["stats"]["monthlyTotals"][0]["month"], "2024-03");
+        // Internal statistics don't leak into the schema
+        assert!(json["stats"].get("rollingAverages").is_none());
+    }
+}
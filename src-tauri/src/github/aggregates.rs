@@ -0,0 +1,175 @@
+//! # Contribution Aggregates
+//!
+//! This module rolls the daily contribution series up into months,
+//! weekdays and weeks. The aggregates are part of `ContributionStats`,
+//! so every frontend view and export uses the same numbers.
+//!
+//! ## Aggregations
+//!
+//! ```text
+//! Vec<ContributionDay>
+//!         │
+//!         ├──▶ monthly_totals ───────▶ [{ month: "2024-03", total, activeDays }]
+//!         │
+//!         ├──▶ weekday_distribution ─▶ [{ weekday: 0 (Sun), total, mean }] × 7
+//!         │
+//!         └──▶ group_into_weeks ─────▶ weekly_totals ──▶ [{ startDate, endDate, total }]
+//! ```
+//!
+//! Best week and best month are the highest totals; ties go to the
+//! most recent period.
+
+use crate::types::{ContributionDay, ContributionWeek, MonthlyTotal, WeekdayStats, WeeklyTotal};
+use chrono::{Datelike, NaiveDate};
+
+/// Sums contributions per calendar month.
+///
+/// # Arguments
+///
+/// * `days` - Slice of contribution days (sorted chronologically)
+///
+/// # Returns
+///
+/// One entry per month present in the data, in chronological order
+pub fn monthly_totals(days: &[ContributionDay]) -> Vec<MonthlyTotal> {
+    let mut months: Vec<MonthlyTotal> = Vec::new();
+
+    for day in days {
+        // "YYYY-MM-DD" ──▶ "YYYY-MM"
+        let Some(month) = day.date.get(..7) else {
+            continue;
+        };
+
+        if months.last().map(|m| m.month.as_str()) != Some(month) {
+            months.push(MonthlyTotal {
+                month: month.to_string(),
+                total: 0,
+                active_days: 0,
+            });
+        }
+
+        if let Some(entry) = months.last_mut() {
+            entry.total += day.count;
+            if day.count > 0 {
+                entry.active_days += 1;
+            }
+        }
+    }
+
+    months
+}
+
+/// Calculates the total and mean contributions for each day of the week.
+///
+/// The mean divides by the number of times the weekday occurs in the
+/// data, so a partial first week doesn't skew it.
+///
+/// # Arguments
+///
+/// * `days` - Slice of contribution days
+///
+/// # Returns
+///
+/// Seven entries, Sunday (0) to Saturday (6)
+pub fn weekday_distribution(days: &[ContributionDay]) -> Vec<WeekdayStats> {
+    let mut totals = [0u32; 7];
+    let mut occurrences = [0u32; 7];
+
+    for day in days {
+        if let Ok(date) = NaiveDate::parse_from_str(&day.date, "%Y-%m-%d") {
+            let weekday = date.weekday().num_days_from_sunday() as usize;
+            totals[weekday] += day.count;
+            occurrences[weekday] += 1;
+        }
+    }
+
+    (0..7)
+        .map(|weekday| WeekdayStats {
+            weekday: weekday as u8,
+            total: totals[weekday],
+            mean: if occurrences[weekday] > 0 {
+                totals[weekday] as f32 / occurrences[weekday] as f32
+            } else {
+                0.0
+            },
+        })
+        .collect()
+}
+
+/// Sums contributions per Sunday-Saturday week.
+///
+/// # Arguments
+///
+/// * `weeks` - Weeks from `group_into_weeks`
+///
+/// # Returns
+///
+/// One entry per week, in chronological order
+pub fn weekly_totals(weeks: &[ContributionWeek]) -> Vec<WeeklyTotal> {
+    weeks
+        .iter()
+        .filter_map(|week| {
+            Some(WeeklyTotal {
+                start_date: week.days.first()?.date.clone(),
+                end_date: week.days.last()?.date.clone(),
+                total: week.days.iter().map(|d| d.count).sum(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the week with the most contributions, if any had activity.
+pub fn best_week(weekly: &[WeeklyTotal]) -> Option<WeeklyTotal> {
+    weekly
+        .iter()
+        .filter(|week| week.total > 0)
+        .max_by_key(|week| week.total)
+        .cloned()
+}
+
+/// Returns the month with the most contributions, if any had activity.
+pub fn best_month(monthly: &[MonthlyTotal]) -> Option<MonthlyTotal> {
+    monthly
+        .iter()
+        .filter(|month| month.total > 0)
+        .max_by_key(|month| month.total)
+        .cloned()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::parser::group_into_weeks;
+    use crate::test_support::days;
+
+    /// Tests monthly, weekday and weekly aggregates on a small series.
+    #[test]
+    fn test_aggregates() {
+        // Wed 2024-01-31 .. Mon 2024-02-05
+        let days = days("2024-01-31", &[2, 0, 4, 1, 6, 3]);
+
+        let monthly = monthly_totals(&days);
+        assert_eq!(monthly.len(), 2);
+        assert_eq!(monthly[0].month, "2024-01");
+        assert_eq!(monthly[1].total, 14);
+        assert_eq!(monthly[1].active_days, 4);
+        assert_eq!(best_month(&monthly).unwrap().month, "2024-02");
+
+        let weekdays = weekday_distribution(&days);
+        assert_eq!(weekdays.len(), 7);
+        assert_eq!(weekdays[0].total, 6); // Sunday
+        assert_eq!(weekdays[2].total, 0); // Tuesday (not in the data)
+        assert_eq!(weekdays[3].mean, 2.0); // Wednesday
+
+        let weekly = weekly_totals(&group_into_weeks(days));
+        assert_eq!(weekly.len(), 2);
+        assert_eq!(weekly[0].start_date, "2024-01-31");
+        assert_eq!(weekly[0].end_date, "2024-02-03");
+        assert_eq!(weekly[0].total, 7);
+        assert_eq!(best_week(&weekly).unwrap().start_date, "2024-02-04");
+    }
+}
@@ -0,0 +1,191 @@
+//! # Avatar Cache
+//!
+//! This module downloads the user's avatar and caches it on disk so the
+//! popup header can show it offline without refetching it every time.
+//!
+//! ## Cache Layout
+//!
+//! ```text
+//! {app_cache_dir}/
+//! └── avatars/
+//!     └── octocat.json   { dataUri, fetchedAt }
+//! ```
+//!
+//! Entries are keyed on the username and expire by age only. The avatar
+//! URL switches between the API URL and the `github.com/{username}.png`
+//! fallback depending on whether the profile could be fetched, and both
+//! point at the same image.
+//!
+//! The avatar is returned as a `data:` URI, which the CSP in
+//! `tauri.conf.json` already allows for images.
+//!
+//! ## Lookup Flow
+//!
+//! ```text
+//! cached_avatar_data_uri("octocat", url, dir)
+//!          │
+//!          ├─── fresh entry for user ──▶ Return cached data URI
+//!          │
+//!          ▼
+//! ┌─────────────────────────┐
+//! │ Download avatar         │──▶ Success ──▶ Write entry, return
+//! └─────────────────────────┘
+//!          │ Failure (offline)
+//!          ▼
+//!    Stale entry? ──▶ Return it, otherwise error
+//! ```
+
+use crate::github::fetcher::build_client;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// How long a cached avatar is used before it is downloaded again (24 hours).
+const AVATAR_MAX_AGE_SECS: i64 = 24 * 60 * 60;
+
+/// A cached avatar stored as JSON in the cache directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedAvatar {
+    /// Avatar encoded as a `data:` URI
+    data_uri: String,
+
+    /// Unix timestamp (seconds) of the download
+    fetched_at: i64,
+}
+
+/// Returns the user's avatar as a `data:` URI, using the disk cache.
+///
+/// # Arguments
+///
+/// * `username` - A normalized GitHub login (used as the cache key)
+/// * `avatar_url` - Remote avatar URL from `UserInfo`, used when the entry is missing or expired
+/// * `cache_dir` - The app cache directory
+///
+/// # Returns
+///
+/// * `Ok(String)` - The avatar as a `data:` URI
+/// * `Err(String)` - Error if the download failed and nothing is cached
+pub async fn cached_avatar_data_uri(
+    username: &str,
+    avatar_url: &str,
+    cache_dir: &Path,
+) -> Result<String, String> {
+    let path = cache_path(cache_dir, username);
+    let cached = read_cached(&path);
+
+    // Use the cached avatar while it's fresh
+    if let Some(entry) = cached.as_ref() {
+        let age = chrono::Utc::now().timestamp() - entry.fetched_at;
+        if age < AVATAR_MAX_AGE_SECS {
+            return Ok(entry.data_uri.clone());
+        }
+    }
+
+    match download_data_uri(avatar_url).await {
+        Ok(data_uri) => {
+            let entry = CachedAvatar {
+                data_uri: data_uri.clone(),
+                fetched_at: chrono::Utc::now().timestamp(),
+            };
+            // A failed write only means we download again next time
+            let _ = write_cached(&path, &entry);
+            Ok(data_uri)
+        }
+        // Offline: a stale avatar is better than a blank one
+        Err(e) => cached.map(|entry| entry.data_uri).ok_or(e),
+    }
+}
+
+/// Downloads an image and encodes it as a `data:` URI.
+async fn download_data_uri(url: &str) -> Result<String, String> {
+    let response = build_client()?
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch avatar: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Avatar request returned status {}", response.status()));
+    }
+
+    let mime = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.split(';').next().unwrap_or(v).trim().to_string())
+        .unwrap_or_else(|| "image/png".to_string());
+
+    if !mime.starts_with("image/") {
+        return Err(format!("Avatar has unexpected content type {}", mime));
+    }
+
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| format!("Failed to read avatar: {}", e))?;
+
+    Ok(to_data_uri(&mime, &bytes))
+}
+
+/// Encodes bytes as a base64 `data:` URI.
+fn to_data_uri(mime: &str, bytes: &[u8]) -> String {
+    format!("data:{};base64,{}", mime, STANDARD.encode(bytes))
+}
+
+/// Path of the cache entry for a user.
+fn cache_path(cache_dir: &Path, username: &str) -> PathBuf {
+    cache_dir.join("avatars").join(format!("{}.json", username))
+}
+
+/// Reads a cache entry, ignoring missing or corrupt files.
+fn read_cached(path: &Path) -> Option<CachedAvatar> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Writes a cache entry, creating the avatars directory if needed.
+fn write_cached(path: &Path, entry: &CachedAvatar) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string(entry).map_err(|e| e.to_string())?;
+    std::fs::write(path, json).map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests data URI encoding.
+    #[test]
+    fn test_to_data_uri() {
+        assert_eq!(to_data_uri("image/png", b"abc"), "data:image/png;base64,YWJj");
+    }
+
+    /// Tests that a fresh cache entry is served without downloading,
+    /// whichever avatar URL the profile resolved to.
+    #[tokio::test]
+    async fn test_cached_avatar_is_reused() {
+        let dir = std::env::temp_dir().join(format!("git-streaks-avatar-{}", std::process::id()));
+        let entry = CachedAvatar {
+            data_uri: to_data_uri("image/png", b"abc"),
+            fetched_at: chrono::Utc::now().timestamp(),
+        };
+        write_cached(&cache_path(&dir, "octocat"), &entry).unwrap();
+
+        for url in [
+            "https://avatars.githubusercontent.com/u/1?v=4&s=80",
+            "https://github.com/octocat.png?size=80",
+        ] {
+            let data_uri = cached_avatar_data_uri("octocat", url, &dir).await.unwrap();
+            assert_eq!(data_uri, entry.data_uri);
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
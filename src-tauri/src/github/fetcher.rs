@@ -39,8 +39,31 @@
 //! - **Network Error**: Connection failed
 //! - **Parse Error**: HTML structure changed
 
-use crate::github::parser::{calculate_stats, group_into_weeks, parse_contribution_html};
-use crate::types::{ContributionData, UserInfo};
+use crate::github::levels::assign_levels;
+use crate::github::parser::{
+    calculate_stats, group_into_weeks, parse_contribution_html, parse_private_contributions,
+    parse_reported_total, reconcile_reported_total,
+};
+use crate::github::profile::{build_user_info, cached_profile};
+use crate::github::username::normalize_username;
+use crate::types::{ContributionData, LevelScale, StreakRules};
+use chrono::NaiveDate;
+
+/// User-Agent sent with every request to GitHub.
+const USER_AGENT: &str = "Git-Streaks/1.0";
+
+/// Builds the HTTP client used for all GitHub requests.
+///
+/// # Returns
+///
+/// * `Ok(reqwest::Client)` - Client with the Git Streaks User-Agent
+/// * `Err(String)` - Error message if the client fails to initialize
+pub(crate) fn build_client() -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .user_agent(USER_AGENT)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))
+}
 
 /// Fetches and parses GitHub contribution data for a user.
 ///
@@ -74,6 +97,11 @@ use crate::types::{ContributionData, UserInfo};
 ///          │
 ///          ▼
 /// ┌─────────────────────────┐
+/// │ Recompute Levels        │──▶ assign_levels()
+/// └─────────────────────────┘
+///          │
+///          ▼
+/// ┌─────────────────────────┐
 /// │ Calculate Stats         │──▶ calculate_stats()
 /// └─────────────────────────┘
 ///          │
@@ -84,6 +112,11 @@ use crate::types::{ContributionData, UserInfo};
 ///          │
 ///          ▼
 /// ┌─────────────────────────┐
+/// │ Fetch Profile Metadata  │──▶ cached_profile() (optional)
+/// └─────────────────────────┘
+///          │
+///          ▼
+/// ┌─────────────────────────┐
 /// │ Return ContributionData │
 /// └─────────────────────────┘
 /// ```
@@ -91,6 +124,9 @@ use crate::types::{ContributionData, UserInfo};
 /// # Arguments
 ///
 /// * `username` - The GitHub username to fetch contributions for
+/// * `today` - Today's date in the user's timezone (reference date for streaks)
+/// * `rules` - Streak rules (rest days) for the relaxed streaks
+/// * `levels` - Scale used to recompute each day's heatmap level
 ///
 /// # Returns
 ///
@@ -100,6 +136,7 @@ use crate::types::{ContributionData, UserInfo};
 /// # Errors
 ///
 /// This function will return an error if:
+/// - The username is not a valid GitHub login
 /// - The HTTP client fails to initialize
 /// - The network request fails
 /// - GitHub returns a non-200 status code
@@ -109,18 +146,31 @@ use crate::types::{ContributionData, UserInfo};
 /// # Example
 ///
 /// ```rust,ignore
-/// let data = fetch_contributions("octocat").await?;
+/// let today = SystemClock.today(&UserTimezone::System);
+/// let data = fetch_contributions(
+///     "octocat",
+///     today,
+///     &StreakRules::default(),
+///     &LevelScale::default(),
+/// )
+/// .await?;
 /// println!("Total contributions: {}", data.stats.total_contributions);
 /// ```
-pub async fn fetch_contributions(username: &str) -> Result<ContributionData, String> {
+pub async fn fetch_contributions(
+    username: &str,
+    today: NaiveDate,
+    rules: &StreakRules,
+    levels: &LevelScale,
+) -> Result<ContributionData, String> {
+    // Reject anything that isn't a valid GitHub login before building a URL
+    let username = normalize_username(username)?;
+    let username = username.as_str();
+
     // Build the GitHub contributions URL
     let url = format!("https://github.com/users/{}/contributions", username);
 
     // Create HTTP client with custom User-Agent
-    let client = reqwest::Client::builder()
-        .user_agent("Git-Streaks/1.0")
-        .build()
-        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+    let client = build_client()?;
 
     // Make the request
     let response = client
@@ -144,31 +194,97 @@ pub async fn fetch_contributions(username: &str) -> Result<ContributionData, Str
         .map_err(|e| format!("Failed to read response: {}", e))?;
 
     // Parse the HTML to extract contribution days
-    let days = parse_contribution_html(&html)?;
+    let mut days = parse_contribution_html(&html)?;
+
+    // Derive levels locally so they always match the counts
+    assign_levels(&mut days, levels);
     
     // Calculate statistics from the parsed days
-    let stats = calculate_stats(&days);
+    let mut stats = calculate_stats(&days, today, rules);
+
+    // Cross-check our total against the one in GitHub's calendar header
+    reconcile_reported_total(&mut stats, parse_reported_total(&html));
     
+    // Detect whether the calendar includes anonymous private contributions
+    let private_contributions = parse_private_contributions(&html);
+
     // Group days into weeks for heatmap rendering
     let weeks = group_into_weeks(days);
 
-    // Construct avatar URL using GitHub's pattern
-    let avatar_url = format!("https://github.com/{}.png?size=80", username);
+    // Enrich the header with profile metadata (cached, non-fatal if rate limited)
+    let profile = cached_profile(&client, username).await;
+    let user = build_user_info(username, profile);
 
     // Build the complete contribution data structure
     let data = ContributionData {
-        user: UserInfo {
-            username: username.to_string(),
-            avatar_url,
-        },
+        user,
         weeks,
         stats,
+        private_contributions,
         last_updated: chrono::Utc::now().to_rfc3339(),
     };
 
     Ok(data)
 }
 
+/// Fetches the total contributions of a past calendar year.
+///
+/// The default endpoint only covers the last 365 days, so a full
+/// calendar year is requested with `from`/`to` query parameters.
+///
+/// ```text
+/// GET /users/octocat/contributions?from=2023-01-01&to=2023-12-31
+///
+/// <h2>1,234 contributions in 2023</h2>
+/// ```
+///
+/// # Arguments
+///
+/// * `username` - The GitHub username
+/// * `year` - The calendar year
+///
+/// # Returns
+///
+/// * `Ok(u32)` - The year's total (header total, or the sum of the days)
+/// * `Err(String)` - Error message if the request or parsing fails
+pub async fn fetch_year_total(username: &str, year: i32) -> Result<u32, String> {
+    let username = normalize_username(username)?;
+    let url = format!(
+        "https://github.com/users/{}/contributions?from={}-01-01&to={}-12-31",
+        username, year, year
+    );
+
+    let response = build_client()?
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch contributions: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "GitHub returned status {}: User may not exist",
+            response.status()
+        ));
+    }
+
+    let html = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+
+    if let Some(total) = parse_reported_total(&html) {
+        return Ok(total);
+    }
+
+    // No header - sum the days that fall in the requested year
+    let prefix = format!("{}-", year);
+    Ok(parse_contribution_html(&html)?
+        .iter()
+        .filter(|day| day.date.starts_with(&prefix))
+        .map(|day| day.count)
+        .sum())
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -180,7 +296,13 @@ mod tests {
     /// Tests that fetching an invalid user returns an error.
     #[tokio::test]
     async fn test_fetch_contributions_invalid_user() {
-        let result = fetch_contributions("this-user-definitely-does-not-exist-12345").await;
+        let result = fetch_contributions(
+            "this-user-definitely-does-not-exist-12345",
+            chrono::Utc::now().date_naive(),
+            &StreakRules::default(),
+            &LevelScale::default(),
+        )
+        .await;
         assert!(result.is_err());
     }
 }
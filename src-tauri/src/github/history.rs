@@ -0,0 +1,176 @@
+//! # Streak History
+//!
+//! `calculate_longest_streak` only keeps the maximum. This module keeps
+//! every streak and the gaps between them, which shows patterns like
+//! "I always break after ~20 days".
+//!
+//! ## History Layout
+//!
+//! ```text
+//! Day    1  2  3  4  5  6  7  8  9 10 11 12
+//! Count  2  1  3  0  0  4  5  0  0  0  1  2
+//!        └──────┘       └───┘          └───┘
+//!        streak 1       streak 2       streak 3
+//!                 └───┘       └──────┘
+//!                 gap 1       gap 2 (longest break)
+//! ```
+//!
+//! Streaks follow the same `StreakRules` as the relaxed streaks: rest
+//! days are skipped and days below the threshold break a streak.
+
+use crate::types::{ContributionDay, StreakGap, StreakHistory, StreakRecord, StreakRules};
+use chrono::NaiveDate;
+
+/// Builds the complete streak history.
+///
+/// # Arguments
+///
+/// * `days` - Slice of contribution days (sorted chronologically)
+/// * `as_of` - Reference date treated as "today"; later days are ignored
+/// * `rules` - Streak rules (rest days, threshold)
+///
+/// # Returns
+///
+/// Every streak and gap in chronological order, plus the longest
+/// break and the days since the last contribution
+pub fn calculate_streak_history(
+    days: &[ContributionDay],
+    as_of: NaiveDate,
+    rules: &StreakRules,
+) -> StreakHistory {
+    let mut streaks: Vec<StreakRecord> = Vec::new();
+    let mut open: Option<(StreakRecord, NaiveDate)> = None; // Running streak and its last date
+    let mut last_contribution: Option<NaiveDate> = None;
+
+    for day in days {
+        let Ok(date) = NaiveDate::parse_from_str(&day.date, "%Y-%m-%d") else {
+            continue;
+        };
+        if date > as_of {
+            break;
+        }
+        if day.count > 0 {
+            last_contribution = Some(date);
+        }
+        if rules.is_rest_day(date) {
+            // Rest days neither extend nor break a streak
+            continue;
+        }
+
+        if !rules.is_streak_day(day.count) {
+            // Below the threshold - close the running streak
+            streaks.extend(open.take().map(|(record, _)| record));
+            continue;
+        }
+
+        match open.as_mut() {
+            Some((record, last)) if date == rules.next_working_day(*last) => {
+                record.count += 1;
+                record.end_date = day.date.clone();
+                record.total_contributions += day.count;
+                *last = date;
+            }
+            _ => {
+                streaks.extend(open.take().map(|(record, _)| record));
+                open = Some((
+                    StreakRecord {
+                        count: 1,
+                        start_date: day.date.clone(),
+                        end_date: day.date.clone(),
+                        total_contributions: day.count,
+                    },
+                    date,
+                ));
+            }
+        }
+    }
+    streaks.extend(open.map(|(record, _)| record));
+
+    let gaps = find_gaps(&streaks);
+    let longest_gap = gaps.iter().max_by_key(|gap| gap.days).cloned();
+
+    StreakHistory {
+        streaks,
+        gaps,
+        longest_gap,
+        days_since_last_contribution: last_contribution
+            .map(|date| (as_of - date).num_days().max(0) as u32),
+    }
+}
+
+/// Returns the `limit` longest streaks, longest first.
+///
+/// Streaks of equal length are ordered most recent first.
+///
+/// # Arguments
+///
+/// * `history` - Streak history from `calculate_streak_history`
+/// * `limit` - Maximum number of streaks to return
+///
+/// # Returns
+///
+/// Up to `limit` streaks sorted by length
+pub fn top_streaks(history: &StreakHistory, limit: usize) -> Vec<StreakRecord> {
+    let mut streaks = history.streaks.clone();
+    // ISO dates sort chronologically as strings
+    streaks.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| b.start_date.cmp(&a.start_date))
+    });
+    streaks.truncate(limit);
+    streaks
+}
+
+/// Records the breaks between consecutive streaks.
+fn find_gaps(streaks: &[StreakRecord]) -> Vec<StreakGap> {
+    streaks
+        .windows(2)
+        .filter_map(|pair| {
+            let end = NaiveDate::parse_from_str(&pair[0].end_date, "%Y-%m-%d").ok()?;
+            let start = NaiveDate::parse_from_str(&pair[1].start_date, "%Y-%m-%d").ok()?;
+
+            Some(StreakGap {
+                start_date: end.succ_opt()?.format("%Y-%m-%d").to_string(),
+                end_date: start.pred_opt()?.format("%Y-%m-%d").to_string(),
+                days: ((start - end).num_days() - 1).max(0) as u32,
+            })
+        })
+        .collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::days;
+
+    /// Tests streak and gap records, matching the diagram at the top of this file.
+    #[test]
+    fn test_streak_history() {
+        let days = days("2024-03-01", &[2, 1, 3, 0, 0, 4, 5, 0, 0, 0, 1, 2]);
+
+        let as_of = NaiveDate::from_ymd_opt(2024, 3, 14).unwrap();
+        let history = calculate_streak_history(&days, as_of, &StreakRules::default());
+
+        assert_eq!(history.streaks.len(), 3);
+        assert_eq!(history.streaks[0].count, 3);
+        assert_eq!(history.streaks[0].total_contributions, 6);
+        assert_eq!(history.streaks[1].start_date, "2024-03-06");
+
+        assert_eq!(history.gaps.len(), 2);
+        let longest_gap = history.longest_gap.clone().unwrap();
+        assert_eq!(longest_gap.days, 3);
+        assert_eq!(longest_gap.start_date, "2024-03-08");
+        assert_eq!(longest_gap.end_date, "2024-03-10");
+        assert_eq!(history.days_since_last_contribution, Some(2));
+
+        // Ties are broken by recency
+        let top = top_streaks(&history, 2);
+        assert_eq!(top[0].start_date, "2024-03-01");
+        assert_eq!(top[1].start_date, "2024-03-11");
+    }
+}
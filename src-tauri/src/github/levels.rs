@@ -0,0 +1,115 @@
+//! # Contribution Levels
+//!
+//! GitHub's `data-level` only describes GitHub's own counts. Once counts
+//! are merged or estimated, the levels have to be derived locally, so
+//! every `ContributionDay` is re-leveled with the same `LevelScale`.
+//!
+//! ## Quartiles
+//!
+//! ```text
+//! Non-zero counts (sorted)   1  2  3  4  5  6  7  8
+//!                                ▲     ▲     ▲
+//!                               Q1    Q2    Q3
+//!
+//! Level                      1  1  2  2  3  3  4  4
+//! ```
+
+use crate::types::{ContributionDay, ContributionWeek, LevelScale};
+
+/// Returns the minimum counts for levels 1 to 4.
+///
+/// # Arguments
+///
+/// * `counts` - Every daily count the scale applies to
+/// * `scale` - Quartiles or fixed thresholds
+fn level_thresholds(counts: impl Iterator<Item = u32>, scale: &LevelScale) -> [u32; 4] {
+    match scale {
+        LevelScale::Thresholds(thresholds) => *thresholds,
+        LevelScale::Quartiles => {
+            let mut active: Vec<u32> = counts.filter(|count| *count > 0).collect();
+            if active.is_empty() {
+                return [1; 4];
+            }
+            active.sort_unstable();
+
+            // Each level starts just above the previous quartile
+            let quartile = |k: usize| active[(active.len() - 1) * k / 4] + 1;
+            [1, quartile(1), quartile(2), quartile(3)]
+        }
+    }
+}
+
+/// Returns the level (0-4) of a count for the given thresholds.
+fn level_for(count: u32, thresholds: &[u32; 4]) -> u8 {
+    if count == 0 {
+        return 0;
+    }
+    thresholds
+        .iter()
+        .filter(|min| count >= **min)
+        .count()
+        .max(1) as u8
+}
+
+/// Recomputes the level of every day from its count.
+///
+/// # Arguments
+///
+/// * `days` - Contribution days to re-level in place
+/// * `scale` - Quartiles or fixed thresholds
+pub fn assign_levels(days: &mut [ContributionDay], scale: &LevelScale) {
+    let thresholds = level_thresholds(days.iter().map(|day| day.count), scale);
+    for day in days {
+        day.level = level_for(day.count, &thresholds);
+    }
+}
+
+/// Recomputes the level of every day in the heatmap weeks.
+///
+/// Quartiles are taken over all weeks together, like `assign_levels`
+/// does for the flat day series.
+///
+/// # Arguments
+///
+/// * `weeks` - Heatmap weeks to re-level in place
+/// * `scale` - Quartiles or fixed thresholds
+pub fn assign_week_levels(weeks: &mut [ContributionWeek], scale: &LevelScale) {
+    let counts = weeks
+        .iter()
+        .flat_map(|week| week.days.iter().map(|day| day.count));
+    let thresholds = level_thresholds(counts, scale);
+    for day in weeks.iter_mut().flat_map(|week| week.days.iter_mut()) {
+        day.level = level_for(day.count, &thresholds);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::days;
+
+    fn levels(days: &[ContributionDay]) -> Vec<u8> {
+        days.iter().map(|day| day.level).collect()
+    }
+
+    /// Tests quartile levels (matching the module diagram) and fixed thresholds.
+    #[test]
+    fn test_assign_levels() {
+        let mut quartiles = days("2024-03-01", &[0, 1, 2, 3, 4, 5, 6, 7, 8]);
+        assign_levels(&mut quartiles, &LevelScale::Quartiles);
+        assert_eq!(levels(&quartiles), [0, 1, 1, 2, 2, 3, 3, 4, 4]);
+
+        let mut fixed = days("2024-03-01", &[0, 1, 3, 4, 7, 8, 14, 15, 40]);
+        assign_levels(&mut fixed, &LevelScale::Thresholds([1, 4, 8, 15]));
+        assert_eq!(levels(&fixed), [0, 1, 1, 2, 2, 3, 3, 4, 4]);
+
+        // A single active day is still visible
+        let mut single = days("2024-03-01", &[0, 0, 5]);
+        assign_levels(&mut single, &LevelScale::Quartiles);
+        assert_eq!(levels(&single), [0, 0, 1]);
+    }
+}
@@ -7,9 +7,15 @@
 //!
 //! ```text
 //! github/
-//! ├── mod.rs      ◀── You are here (public exports)
-//! ├── fetcher.rs  ── HTTP client for fetching GitHub data
-//! └── parser.rs   ── HTML parsing and statistics calculation
+//! ├── mod.rs        ◀── You are here (public exports)
+//! ├── aggregates.rs ── Per-month, per-weekday and per-week totals
+//! ├── avatar.rs     ── Avatar download and disk cache
+//! ├── fetcher.rs    ── HTTP client for fetching GitHub data
+//! ├── history.rs    ── Every streak and the gaps between them
+//! ├── levels.rs     ── Heatmap levels derived from the counts
+//! ├── parser.rs     ── HTML parsing and statistics calculation
+//! ├── profile.rs    ── Public profile metadata (REST API)
+//! └── username.rs   ── Username normalization and validation
 //! ```
 //!
 //! ## Data Pipeline
@@ -40,6 +46,7 @@
 //! │    - Best day (max contributions)                               │
 //! │    - Current streak (consecutive days ending today/yesterday)   │
 //! │    - Longest streak (highest consecutive days ever)             │
+//! │    - Monthly, weekday and weekly aggregates                     │
 //! └─────────────────────────────────────────────────────────────────┘
 //!                              │
 //!                              ▼
@@ -51,13 +58,34 @@
 //!                              │
 //!                              ▼
 //! ┌─────────────────────────────────────────────────────────────────┐
-//! │ 5. Return ContributionData                                      │
+//! │ 5. Fetch Profile                                                │
+//! │    GET https://api.github.com/users/{username}                  │
+//! │    Display name, avatar, bio, followers (optional)              │
+//! └─────────────────────────────────────────────────────────────────┘
+//!                              │
+//!                              ▼
+//! ┌─────────────────────────────────────────────────────────────────┐
+//! │ 6. Return ContributionData                                      │
 //! │    Complete data structure ready for frontend                   │
 //! └─────────────────────────────────────────────────────────────────┘
 //! ```
 
+mod aggregates;
+mod avatar;
 mod fetcher;
+mod history;
+mod levels;
 mod parser;
+mod profile;
+mod username;
 
 // Re-export the main fetch function for use by the rest of the application
-pub use fetcher::fetch_contributions;
+pub use avatar::cached_avatar_data_uri;
+pub use fetcher::{fetch_contributions, fetch_year_total};
+pub use history::{calculate_streak_history, top_streaks};
+pub use levels::assign_week_levels;
+pub use parser::{
+    calculate_range_stats, calculate_stats, calculate_streak_timeline, group_into_weeks,
+    recalculate_stats, resolve_range_preset,
+};
+pub use username::{normalize_username, validate_username};
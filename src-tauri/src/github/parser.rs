@@ -31,6 +31,10 @@
 //!
 //! If the count isn't available in the HTML, we estimate it from the level.
 //!
+//! The calendar header ("1,234 contributions in the last year") is parsed
+//! separately so the computed total can be cross-checked against GitHub's.
+//! Private contribution markers are detected the same way.
+//!
 //! ## Streak Calculation
 //!
 //! ```text
@@ -39,6 +43,10 @@
 //! A current streak is a sequence of consecutive days with
 //! contributions that ends on TODAY or YESTERDAY.
 //!
+//! "Today" is a parameter: the app passes today's date in the tracked
+//! user's timezone (see `clock.rs` and `timezone.rs`), while the "as of"
+//! API passes any past date to replay history.
+//!
 //! Example (today is Jan 5):
 //! Jan 1  Jan 2  Jan 3  Jan 4  Jan 5
 //!   3      5      2      1      0    ◀── Current streak = 4 days
@@ -48,9 +56,29 @@
 //! ─────────────────────────────────────────────────────
 //! The longest sequence of consecutive days with contributions
 //! at any point in the contribution history.
+//!
+//! Streak days need at least `StreakRules::min_daily` contributions
+//! (default 1); quieter days count as days without contributions.
+//!
+//! Relaxed Streaks (rest days):
+//! ─────────────────────────────────────────────────────
+//! Rest weekdays from `StreakRules` are skipped entirely: they
+//! neither extend nor break a streak.
+//!
+//! Example (rest days: Sat, Sun):
+//! Thu  Fri  Sat  Sun  Mon
+//!  2    4    0    0    3    ◀── Strict streak = 1 day (Mon)
+//!                              Relaxed streak = 3 days (Thu, Fri, Mon)
 //! ```
 
-use crate::types::{BestDay, ContributionDay, ContributionStats, ContributionWeek, Streak};
+use crate::github::aggregates::{
+    best_month, best_week, monthly_totals, weekday_distribution, weekly_totals,
+};
+use crate::types::{
+    ActiveDayShares, BestDay, ContributionData, ContributionDay, ContributionStats,
+    ContributionWeek, PeriodChange, PrivateContributions, RangePreset, RangeStats, RollingAverages,
+    Streak, StreakRules, StreakTimelinePoint, Trend,
+};
 use chrono::{Datelike, NaiveDate};
 use regex::Regex;
 
@@ -151,6 +179,96 @@ pub fn parse_contribution_html(html: &str) -> Result<Vec<ContributionDay>, Strin
     Ok(days)
 }
 
+/// Parses the total from the calendar header.
+///
+/// GitHub renders a heading above the calendar that states the total
+/// for the displayed period. Unlike our computed total, this number is
+/// never estimated, so it is used to detect when level-based estimates
+/// were needed.
+///
+/// ```html
+/// <h2 id="js-contribution-activity-description">
+///   1,234
+///   contributions
+///   in the last year
+/// </h2>
+/// ```
+///
+/// # Arguments
+///
+/// * `html` - Raw HTML string from GitHub's contribution endpoint
+///
+/// # Returns
+///
+/// The reported total, or `None` if the header isn't present
+pub fn parse_reported_total(html: &str) -> Option<u32> {
+    // Pattern: "1,234 contributions in the last year" (or "in 2023" for year views)
+    let header_re =
+        Regex::new(r"([\d,]+)\s+contributions?\s+in\s+(?:the\s+last\s+year|\d{4})").ok()?;
+
+    header_re
+        .captures(html)
+        .and_then(|cap| cap.get(1))
+        .and_then(|m| m.as_str().replace(',', "").parse().ok())
+}
+
+/// Records GitHub's reported total in the statistics.
+///
+/// Flags the statistics as estimated when the reported total
+/// disagrees with the sum of the parsed daily counts.
+///
+/// # Arguments
+///
+/// * `stats` - Statistics calculated from the parsed days
+/// * `reported_total` - Total parsed from the calendar header
+pub fn reconcile_reported_total(stats: &mut ContributionStats, reported_total: Option<u32>) {
+    stats.reported_total = reported_total;
+    stats.total_is_estimate = reported_total
+        .map(|reported| reported != stats.total_contributions)
+        .unwrap_or(false);
+}
+
+/// Detects private contribution markers in the calendar HTML.
+///
+/// GitHub adds private work to the calendar as anonymous counts when
+/// the user enables "Private contributions". The HTML then contains
+/// the setting label as an element of its own, or the activity summary:
+///
+/// ```html
+/// <span class="...">Private contributions</span>
+/// <span>12 contributions in private repositories</span>
+/// ```
+///
+/// Free text that merely mentions private contributions (e.g. a bio)
+/// is not a marker. GitHub may repeat the summary, so only the first
+/// count is used.
+///
+/// # Arguments
+///
+/// * `html` - Raw HTML string from GitHub's contribution endpoint
+///
+/// # Returns
+///
+/// The detected markers, with the count if GitHub states one
+pub fn parse_private_contributions(html: &str) -> PrivateContributions {
+    // Pattern: "12 contributions in private repositories"
+    let count_re =
+        Regex::new(r"([\d,]+)\s+contributions?\s+in\s+private\s+repositor(?:y|ies)\b").ok();
+    // Pattern: the "Private contributions" label as the whole text of an element
+    let marker_re = Regex::new(r">\s*Private contributions\s*<").ok();
+
+    let count: Option<u32> = count_re
+        .and_then(|re| re.captures(html))
+        .and_then(|cap| cap.get(1)?.as_str().replace(',', "").parse().ok());
+
+    let has_marker = marker_re.map(|re| re.is_match(html)).unwrap_or(false);
+
+    PrivateContributions {
+        included: has_marker || count.is_some(),
+        count,
+    }
+}
+
 /// Estimates contribution count from the visual level.
 ///
 /// When GitHub doesn't provide explicit counts in the HTML,
@@ -265,8 +383,13 @@ pub fn group_into_weeks(days: Vec<ContributionDay>) -> Vec<ContributionWeek> {
 /// - Total contributions
 /// - Best day (highest single-day count)
 /// - Average contributions per day
-/// - Current streak
-/// - Longest streak
+/// - Current and longest streak (strict: every day counts)
+/// - Current and longest streak (relaxed: rest days are skipped)
+/// - Current and longest weekly and monthly streaks
+/// - Per-month, per-weekday and per-week aggregates (see `aggregates`)
+/// - Rolling 7/30/90-day averages, period-over-period change and trend
+/// - Median, standard deviation, active-day shares, level histogram and
+///   consistency score
 ///
 /// ## Statistics Flow
 ///
@@ -279,19 +402,41 @@ pub fn group_into_weeks(days: Vec<ContributionDay>) -> Vec<ContributionWeek> {
 ///         │
 ///         ├──▶ total / len ─────────────▶ average_per_day
 ///         │
-///         ├──▶ calculate_current_streak ─▶ current_streak
+///         ├──▶ calculate_current_streak ─▶ current_streak, relaxed_current_streak
+///         │
+///         ├──▶ calculate_longest_streak ─▶ longest_streak, relaxed_longest_streak
+///         │
+///         ├──▶ calculate_period_streaks ─▶ weekly and monthly streaks
 ///         │
-///         └──▶ calculate_longest_streak ─▶ longest_streak
+///         ├──▶ aggregates ───────────────▶ monthly/weekly totals, weekday distribution
+///         │
+///         ├──▶ calculate_rolling_averages ─▶ rolling averages, changes, trend
+///         │
+///         └──▶ distribution helpers ─────▶ median, deviation, histogram, consistency
 /// ```
 ///
+/// Only days up to and including `as_of` are considered, and the
+/// current streak must end on `as_of` or the day before. Passing
+/// today's date gives the live statistics; passing a past date
+/// answers "what were my stats on March 3rd".
+///
 /// # Arguments
 ///
-/// * `days` - Slice of contribution days
+/// * `days` - Slice of contribution days (sorted chronologically)
+/// * `as_of` - Reference date treated as "today"
+/// * `rules` - Streak rules (rest days, thresholds)
 ///
 /// # Returns
 ///
 /// Computed statistics
-pub fn calculate_stats(days: &[ContributionDay]) -> ContributionStats {
+pub fn calculate_stats(
+    days: &[ContributionDay],
+    as_of: NaiveDate,
+    rules: &StreakRules,
+) -> ContributionStats {
+    // Ignore anything after the reference date
+    let days = days_up_to(days, as_of);
+
     // Calculate total contributions
     let total_contributions: u32 = days.iter().map(|d| d.count).sum();
 
@@ -316,9 +461,34 @@ pub fn calculate_stats(days: &[ContributionDay]) -> ContributionStats {
         0.0
     };
 
-    // Calculate streak statistics
-    let current_streak = calculate_current_streak(days);
-    let longest_streak = calculate_longest_streak(days);
+    // Calculate strict streaks (every day counts) and relaxed streaks (rest days skipped)
+    let strict = rules.without_rest_days();
+    let current_streak = calculate_current_streak(days, as_of, &strict);
+    let longest_streak = calculate_longest_streak(days, &strict);
+    let relaxed_current_streak = calculate_current_streak(days, as_of, rules);
+    let relaxed_longest_streak = calculate_longest_streak(days, rules);
+
+    // Weeks need `weekly_target` contributions, months any activity
+    let weeks = group_into_weeks(days.to_vec());
+    let (current_weekly_streak, longest_weekly_streak) = calculate_period_streaks(
+        &week_periods(&weeks),
+        week_index(as_of),
+        rules.weekly_target.max(1),
+    );
+    let (current_monthly_streak, longest_monthly_streak) =
+        calculate_period_streaks(&month_periods(days), month_index(as_of), 1);
+
+    // Per-month, per-weekday and per-week aggregates
+    let monthly_totals = monthly_totals(days);
+    let weekly_totals = weekly_totals(&weeks);
+
+    // Recent activity compared with the periods before it
+    let rolling_averages = calculate_rolling_averages(days, as_of);
+    let trend = calculate_trend(&rolling_averages);
+
+    // How evenly the contributions are spread
+    let counts: Vec<u32> = days.iter().map(|d| d.count).collect();
+    let consistency_score = calculate_consistency_score(days, &weeks);
 
     ContributionStats {
         total_contributions,
@@ -326,15 +496,238 @@ pub fn calculate_stats(days: &[ContributionDay]) -> ContributionStats {
         average_per_day,
         current_streak,
         longest_streak,
+        relaxed_current_streak,
+        relaxed_longest_streak,
+        rest_days: rules.rest_days.clone(),
+        streak_threshold: rules.min_daily.max(1),
+        current_weekly_streak,
+        longest_weekly_streak,
+        weekly_target: rules.weekly_target.max(1),
+        current_monthly_streak,
+        longest_monthly_streak,
+        best_week: best_week(&weekly_totals),
+        best_month: best_month(&monthly_totals),
+        monthly_totals,
+        weekday_distribution: weekday_distribution(days),
+        weekly_totals,
+        week_over_week: calculate_period_change(days, as_of, 7),
+        month_over_month: calculate_period_change(days, as_of, 30),
+        rolling_averages,
+        trend,
+        median_per_day: median(&counts),
+        std_deviation: std_deviation(&counts, average_per_day),
+        active_days: ActiveDayShares {
+            last_30_days: active_share(days, as_of, 30),
+            last_90_days: active_share(days, as_of, 90),
+            last_365_days: active_share(days, as_of, 365),
+        },
+        level_histogram: level_histogram(days),
+        consistency_score,
+        reported_total: None,
+        total_is_estimate: false,
+    }
+}
+
+/// Recalculates the statistics of already-fetched contribution data.
+///
+/// Used when "today" changes (midnight rollover) or when settings that
+/// affect the statistics change, without refetching from GitHub. The
+/// reported total from the calendar header is preserved.
+///
+/// # Arguments
+///
+/// * `data` - Contribution data whose statistics should be updated
+/// * `today` - Today's date in the tracked user's timezone
+/// * `rules` - Streak rules for the relaxed streaks
+pub fn recalculate_stats(data: &mut ContributionData, today: NaiveDate, rules: &StreakRules) {
+    let days = data.days();
+    let reported_total = data.stats.reported_total;
+
+    data.stats = calculate_stats(&days, today, rules);
+    reconcile_reported_total(&mut data.stats, reported_total);
+}
+
+/// Returns the prefix of `days` up to and including `as_of`.
+///
+/// # Arguments
+///
+/// * `days` - Slice of contribution days (sorted chronologically)
+/// * `as_of` - Last date to include
+fn days_up_to(days: &[ContributionDay], as_of: NaiveDate) -> &[ContributionDay] {
+    // ISO dates sort lexicographically, so the string comparison is chronological
+    let as_of = as_of.format("%Y-%m-%d").to_string();
+    let end = days.partition_point(|day| day.date <= as_of);
+    &days[..end]
+}
+
+/// Resolves a range preset to the dates shown by the matching heatmap view.
+///
+/// Like the heatmap, the range starts on the Sunday of the week containing
+/// the cutoff so the stats cover exactly the visible columns.
+///
+/// ```text
+/// Preset        Cutoff           Range
+/// ─────────────────────────────────────────────────────
+/// 30d           today - 30 days  Sunday before cutoff ..= today
+/// 12w           today - 84 days  Sunday before cutoff ..= today
+/// year          -                first cached day ..= today
+/// ```
+///
+/// # Arguments
+///
+/// * `preset` - The heatmap view
+/// * `today` - Today's date in the user's timezone
+/// * `first_day` - First day of the cached data
+///
+/// # Returns
+///
+/// The inclusive `(start, end)` range
+pub fn resolve_range_preset(
+    preset: RangePreset,
+    today: NaiveDate,
+    first_day: NaiveDate,
+) -> (NaiveDate, NaiveDate) {
+    let cutoff = match preset {
+        RangePreset::Last30Days => today - chrono::Duration::days(30),
+        RangePreset::Last12Weeks => today - chrono::Duration::days(84),
+        RangePreset::Year => return (first_day, today),
+    };
+
+    let week_start =
+        cutoff - chrono::Duration::days(cutoff.weekday().num_days_from_sunday() as i64);
+    (week_start.max(first_day), today)
+}
+
+/// Calculates statistics and heatmap weeks for a date range.
+///
+/// Only days inside `start..=end` are used, so the stat cards match the
+/// heatmap view the user is looking at. Streaks are evaluated as of
+/// `end` (or `as_of`, if earlier).
+///
+/// # Arguments
+///
+/// * `days` - Slice of contribution days (sorted chronologically)
+/// * `start` - First date of the range
+/// * `end` - Last date of the range
+/// * `as_of` - Today's date in the user's timezone
+/// * `rules` - Streak rules
+///
+/// # Returns
+///
+/// Statistics and grouped weeks for the range
+pub fn calculate_range_stats(
+    days: &[ContributionDay],
+    start: NaiveDate,
+    end: NaiveDate,
+    as_of: NaiveDate,
+    rules: &StreakRules,
+) -> RangeStats {
+    let start_key = start.format("%Y-%m-%d").to_string();
+    let in_range: Vec<ContributionDay> = days_up_to(days, end)
+        .iter()
+        .filter(|day| day.date >= start_key)
+        .cloned()
+        .collect();
+
+    RangeStats {
+        start_date: start_key,
+        end_date: end.format("%Y-%m-%d").to_string(),
+        stats: calculate_stats(&in_range, end.min(as_of), rules),
+        weeks: group_into_weeks(in_range),
     }
 }
 
+/// Builds a day-by-day timeline of streak values.
+///
+/// For every date in `start..=end`, records what the current and
+/// longest streak were on that date, as if the statistics had been
+/// calculated that day. This is a single pass over the days, so a
+/// full year of history is cheap to replay.
+///
+/// ## Example
+///
+/// ```text
+/// Date     Jan 1  Jan 2  Jan 3  Jan 4  Jan 5  Jan 6
+/// Count      3      5      0      0      2      1
+/// Current    1      2      2      0      1      2
+/// Longest    1      2      2      2      2      2
+///                          ▲
+///                          └── Jan 2 still counts ("yesterday")
+/// ```
+///
+/// # Arguments
+///
+/// * `days` - Slice of contribution days (sorted chronologically)
+/// * `start` - First date of the timeline
+/// * `end` - Last date of the timeline
+/// * `rules` - Streak rules (rest days are skipped)
+///
+/// # Returns
+///
+/// One point per date in the range (empty if `start > end`)
+pub fn calculate_streak_timeline(
+    days: &[ContributionDay],
+    start: NaiveDate,
+    end: NaiveDate,
+    rules: &StreakRules,
+) -> Vec<StreakTimelinePoint> {
+    let counts: std::collections::HashMap<NaiveDate, u32> = days
+        .iter()
+        .filter_map(|day| {
+            NaiveDate::parse_from_str(&day.date, "%Y-%m-%d")
+                .ok()
+                .map(|date| (date, day.count))
+        })
+        .collect();
+
+    // Start counting from the first known day so streaks that began
+    // before `start` are carried into the timeline
+    let first_day = counts.keys().min().copied().unwrap_or(start).min(start);
+
+    let mut timeline = Vec::new();
+    let mut run: u32 = 0; // Active days ending on the latest non-rest date
+    let mut longest: u32 = 0;
+
+    for date in first_day.iter_days().take_while(|date| *date <= end) {
+        let current_streak = if rules.is_rest_day(date) {
+            // Rest days neither extend nor break the streak
+            run
+        } else {
+            let active = rules.is_streak_day(counts.get(&date).copied().unwrap_or(0));
+            let previous_run = run; // Active days ending on the previous working date
+            run = if active { run + 1 } else { 0 };
+            longest = longest.max(run);
+
+            // Today may still get contributions, so yesterday's streak counts
+            if active {
+                run
+            } else {
+                previous_run
+            }
+        };
+
+        if date >= start {
+            timeline.push(StreakTimelinePoint {
+                date: date.format("%Y-%m-%d").to_string(),
+                current_streak,
+                longest_streak: longest,
+            });
+        }
+    }
+
+    timeline
+}
+
 /// Calculates the current active streak.
 ///
 /// A current streak is a sequence of consecutive days with contributions
 /// that ends on today or yesterday. If today has no contributions but
 /// yesterday did, the streak still counts (user might contribute later).
 ///
+/// Rest days from `rules` are skipped, so "yesterday" becomes the last
+/// working day before today (e.g. Friday when today is Monday and
+/// weekends are rest days).
+///
 /// ## Algorithm
 ///
 /// ```text
@@ -360,11 +753,17 @@ pub fn calculate_stats(days: &[ContributionDay]) -> ContributionStats {
 /// # Arguments
 ///
 /// * `days` - Slice of contribution days (sorted chronologically)
+/// * `today` - Today's date in the tracked user's timezone
+/// * `rules` - Streak rules (rest days are skipped)
 ///
 /// # Returns
 ///
 /// The current streak, or an empty streak if none exists
-fn calculate_current_streak(days: &[ContributionDay]) -> Streak {
+fn calculate_current_streak(
+    days: &[ContributionDay],
+    today: NaiveDate,
+    rules: &StreakRules,
+) -> Streak {
     if days.is_empty() {
         return Streak {
             count: 0,
@@ -373,15 +772,19 @@ fn calculate_current_streak(days: &[ContributionDay]) -> Streak {
         };
     }
 
-    let today = chrono::Local::now().naive_local().date();
-    let yesterday = today - chrono::Duration::days(1);
+    let yesterday = rules.previous_working_day(today);
 
     // Work backwards from most recent day
     let mut streak_days: Vec<&ContributionDay> = Vec::new();
 
     for day in days.iter().rev() {
         if let Ok(date) = NaiveDate::parse_from_str(&day.date, "%Y-%m-%d") {
-            if day.count > 0 {
+            if rules.is_rest_day(date) {
+                // Rest days neither extend nor break the streak
+                continue;
+            }
+
+            if rules.is_streak_day(day.count) {
                 // Check if this day can be part of current streak
                 if streak_days.is_empty() {
                     // First day must be today or yesterday
@@ -397,14 +800,14 @@ fn calculate_current_streak(days: &[ContributionDay]) -> Streak {
                         "%Y-%m-%d",
                     )
                     .unwrap();
-                    if date == last_date - chrono::Duration::days(1) {
+                    if date == rules.previous_working_day(last_date) {
                         streak_days.push(day);
                     } else {
                         break;
                     }
                 }
             } else if !streak_days.is_empty() {
-                // Zero contributions breaks the streak
+                // A day below the threshold breaks the streak
                 break;
             } else if date < yesterday {
                 // Haven't found start of streak and we're past yesterday
@@ -431,7 +834,8 @@ fn calculate_current_streak(days: &[ContributionDay]) -> Streak {
 /// Calculates the longest streak ever achieved.
 ///
 /// Scans through all days to find the longest sequence of
-/// consecutive days with contributions.
+/// consecutive days with contributions. Rest days from `rules`
+/// are skipped.
 ///
 /// ## Algorithm
 ///
@@ -463,11 +867,12 @@ fn calculate_current_streak(days: &[ContributionDay]) -> Streak {
 /// # Arguments
 ///
 /// * `days` - Slice of contribution days (sorted chronologically)
+/// * `rules` - Streak rules (rest days are skipped)
 ///
 /// # Returns
 ///
 /// The longest streak found
-fn calculate_longest_streak(days: &[ContributionDay]) -> Streak {
+fn calculate_longest_streak(days: &[ContributionDay], rules: &StreakRules) -> Streak {
     if days.is_empty() {
         return Streak {
             count: 0,
@@ -488,10 +893,15 @@ fn calculate_longest_streak(days: &[ContributionDay]) -> Streak {
 
     for day in days.iter() {
         if let Ok(date) = NaiveDate::parse_from_str(&day.date, "%Y-%m-%d") {
-            if day.count > 0 {
-                // Check if consecutive with previous day
+            if rules.is_rest_day(date) {
+                // Rest days neither extend nor break the streak
+                continue;
+            }
+
+            if rules.is_streak_day(day.count) {
+                // Check if consecutive with previous (working) day
                 let is_consecutive = last_date
-                    .map(|ld| date == ld + chrono::Duration::days(1))
+                    .map(|ld| date == rules.next_working_day(ld))
                     .unwrap_or(false);
 
                 if is_consecutive && current_start.is_some() {
@@ -514,7 +924,7 @@ fn calculate_longest_streak(days: &[ContributionDay]) -> Streak {
 
                 last_date = Some(date);
             } else {
-                // Zero contributions - reset current streak
+                // Below the threshold - reset current streak
                 current_start = None;
                 current_count = 0;
                 last_date = Some(date);
@@ -525,6 +935,365 @@ fn calculate_longest_streak(days: &[ContributionDay]) -> Streak {
     longest
 }
 
+// ============================================================================
+// Weekly and Monthly Streaks
+// ============================================================================
+
+/// A calendar week or month with its total contributions.
+struct Period {
+    /// Sequential index (consecutive periods differ by 1)
+    index: i32,
+
+    /// First day of the period in the data (YYYY-MM-DD)
+    start_date: String,
+
+    /// Last day of the period in the data (YYYY-MM-DD)
+    end_date: String,
+
+    /// Total contributions in the period
+    total: u32,
+}
+
+/// Index of the Sunday-Saturday week containing `date`.
+fn week_index(date: NaiveDate) -> i32 {
+    let sunday = date - chrono::Duration::days(date.weekday().num_days_from_sunday() as i64);
+    sunday.num_days_from_ce() / 7
+}
+
+/// Index of the calendar month containing `date`.
+fn month_index(date: NaiveDate) -> i32 {
+    date.year() * 12 + date.month0() as i32
+}
+
+/// Turns the weeks from `group_into_weeks` into periods.
+fn week_periods(weeks: &[ContributionWeek]) -> Vec<Period> {
+    weeks
+        .iter()
+        .filter_map(|week| {
+            let first = week.days.first()?;
+            let last = week.days.last()?;
+            let date = NaiveDate::parse_from_str(&first.date, "%Y-%m-%d").ok()?;
+
+            Some(Period {
+                index: week_index(date),
+                start_date: first.date.clone(),
+                end_date: last.date.clone(),
+                total: week.days.iter().map(|d| d.count).sum(),
+            })
+        })
+        .collect()
+}
+
+/// Groups days into calendar month periods.
+fn month_periods(days: &[ContributionDay]) -> Vec<Period> {
+    let mut periods: Vec<Period> = Vec::new();
+
+    for day in days {
+        let Ok(date) = NaiveDate::parse_from_str(&day.date, "%Y-%m-%d") else {
+            continue;
+        };
+        let index = month_index(date);
+
+        match periods.last_mut() {
+            Some(period) if period.index == index => {
+                period.end_date = day.date.clone();
+                period.total += day.count;
+            }
+            _ => periods.push(Period {
+                index,
+                start_date: day.date.clone(),
+                end_date: day.date.clone(),
+                total: day.count,
+            }),
+        }
+    }
+
+    periods
+}
+
+/// Calculates the current and longest streak of consecutive periods
+/// (weeks or months) that reach `target` contributions.
+///
+/// Like the daily streak, the period containing "today" gets grace:
+/// if it hasn't reached the target yet, a streak ending in the
+/// previous period is still current.
+///
+/// ```text
+/// Weekly target: 5
+///
+/// Week    W1   W2   W3   W4   W5 (this week)
+/// Total    8    2    6    9    1
+///                    └────┘    ◀── Current = 2 weeks (W5 still open)
+/// ```
+///
+/// # Arguments
+///
+/// * `periods` - Periods in chronological order
+/// * `current_index` - Index of the period containing "today"
+/// * `target` - Minimum contributions for a period to count
+///
+/// # Returns
+///
+/// The current and longest streak, counted in periods
+fn calculate_period_streaks(
+    periods: &[Period],
+    current_index: i32,
+    target: u32,
+) -> (Streak, Streak) {
+    let empty = || Streak {
+        count: 0,
+        start_date: String::new(),
+        end_date: String::new(),
+    };
+
+    // Longest: scan forwards over consecutive qualifying periods
+    let mut longest = empty();
+    let mut run: Vec<&Period> = Vec::new();
+    for period in periods {
+        let is_consecutive = run
+            .last()
+            .is_some_and(|last| period.index == last.index + 1);
+        if period.total < target {
+            run.clear();
+            continue;
+        }
+        if !is_consecutive {
+            run.clear();
+        }
+        run.push(period);
+
+        if run.len() as u32 > longest.count {
+            longest = Streak {
+                count: run.len() as u32,
+                start_date: run[0].start_date.clone(),
+                end_date: period.end_date.clone(),
+            };
+        }
+    }
+
+    // Current: walk backwards from the period containing "today"
+    let mut current: Vec<&Period> = Vec::new();
+    let mut expected = current_index;
+    for period in periods.iter().rev() {
+        if period.index > current_index {
+            continue;
+        }
+        if period.index == current_index && period.total < target {
+            // This period is still open
+            expected = current_index - 1;
+            continue;
+        }
+        if period.index != expected || period.total < target {
+            break;
+        }
+        current.push(period);
+        expected -= 1;
+    }
+
+    let current = match (current.last(), current.first()) {
+        (Some(first), Some(last)) => Streak {
+            count: current.len() as u32,
+            start_date: first.start_date.clone(),
+            end_date: last.end_date.clone(),
+        },
+        _ => empty(),
+    };
+
+    (current, longest)
+}
+
+// ============================================================================
+// Rolling Averages and Trends
+// ============================================================================
+
+/// A trend only counts as up or down when the 7-day average differs
+/// from the 30-day average by more than this fraction.
+const TREND_TOLERANCE: f32 = 0.1;
+
+/// Sums the contributions in a window of `len` days ending `offset`
+/// days before `as_of`.
+///
+/// ```text
+///             offset = 7, len = 7        offset = 0, len = 7
+///          ┌──────────────────────┐   ┌──────────────────────┐
+/// ... ─────┤  previous 7 days     ├───┤  last 7 days         ├── as_of
+///          └──────────────────────┘   └──────────────────────┘
+/// ```
+///
+/// # Returns
+///
+/// The sum and the number of days in the window that have data
+fn window_sum(days: &[ContributionDay], as_of: NaiveDate, offset: i64, len: i64) -> (u32, u32) {
+    let last = as_of - chrono::Duration::days(offset);
+    let first = last - chrono::Duration::days(len - 1);
+
+    days.iter()
+        .filter_map(|day| {
+            let date = NaiveDate::parse_from_str(&day.date, "%Y-%m-%d").ok()?;
+            (first <= date && date <= last).then_some(day.count)
+        })
+        .fold((0, 0), |(sum, present), count| (sum + count, present + 1))
+}
+
+/// Average contributions per day over the last `len` days.
+///
+/// Divides by the days that have data, so a short history isn't
+/// diluted by days before the calendar starts.
+fn rolling_average(days: &[ContributionDay], as_of: NaiveDate, len: i64) -> f32 {
+    let (sum, present) = window_sum(days, as_of, 0, len);
+    if present > 0 {
+        sum as f32 / present as f32
+    } else {
+        0.0
+    }
+}
+
+/// Calculates the rolling 7, 30 and 90-day averages ending on `as_of`.
+fn calculate_rolling_averages(days: &[ContributionDay], as_of: NaiveDate) -> RollingAverages {
+    RollingAverages {
+        last_7_days: rolling_average(days, as_of, 7),
+        last_30_days: rolling_average(days, as_of, 30),
+        last_90_days: rolling_average(days, as_of, 90),
+    }
+}
+
+/// Compares the last `len` days with the `len` days before them.
+///
+/// Used with 7 days for week-over-week and 30 days for
+/// month-over-month change.
+///
+/// # Arguments
+///
+/// * `days` - Slice of contribution days (sorted chronologically)
+/// * `as_of` - Last day of the current period
+/// * `len` - Period length in days
+///
+/// # Returns
+///
+/// Both totals and the percentage change (`None` if the previous period was empty)
+fn calculate_period_change(days: &[ContributionDay], as_of: NaiveDate, len: i64) -> PeriodChange {
+    let (current, _) = window_sum(days, as_of, 0, len);
+    let (previous, _) = window_sum(days, as_of, len, len);
+
+    PeriodChange {
+        current,
+        previous,
+        change_percent: (previous > 0)
+            .then(|| (current as f32 - previous as f32) / previous as f32 * 100.0),
+    }
+}
+
+/// Derives the trend direction from the 7-day vs. 30-day average.
+///
+/// ```text
+/// 7-day avg > 30-day avg × 1.1  ──▶ Up
+/// 7-day avg < 30-day avg × 0.9  ──▶ Down
+/// otherwise                     ──▶ Flat
+/// ```
+fn calculate_trend(averages: &RollingAverages) -> Trend {
+    let baseline = averages.last_30_days;
+
+    if averages.last_7_days > baseline * (1.0 + TREND_TOLERANCE) {
+        Trend::Up
+    } else if averages.last_7_days < baseline * (1.0 - TREND_TOLERANCE) {
+        Trend::Down
+    } else {
+        Trend::Flat
+    }
+}
+
+// ============================================================================
+// Distribution and Consistency
+// ============================================================================
+
+/// Returns the median of the daily counts.
+fn median(counts: &[u32]) -> f32 {
+    if counts.is_empty() {
+        return 0.0;
+    }
+
+    let mut sorted = counts.to_vec();
+    sorted.sort_unstable();
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 1 {
+        sorted[mid] as f32
+    } else {
+        (sorted[mid - 1] + sorted[mid]) as f32 / 2.0
+    }
+}
+
+/// Returns the (population) standard deviation of the daily counts.
+fn std_deviation(counts: &[u32], mean: f32) -> f32 {
+    if counts.is_empty() {
+        return 0.0;
+    }
+
+    let variance = counts
+        .iter()
+        .map(|count| (*count as f32 - mean).powi(2))
+        .sum::<f32>()
+        / counts.len() as f32;
+    variance.sqrt()
+}
+
+/// Percentage of active days among the days with data in the last `len` days.
+fn active_share(days: &[ContributionDay], as_of: NaiveDate, len: i64) -> f32 {
+    let first = as_of - chrono::Duration::days(len - 1);
+    let (active, present) = days
+        .iter()
+        .filter(|day| {
+            NaiveDate::parse_from_str(&day.date, "%Y-%m-%d")
+                .map(|date| first <= date && date <= as_of)
+                .unwrap_or(false)
+        })
+        .fold((0u32, 0u32), |(active, present), day| {
+            (active + u32::from(day.count > 0), present + 1)
+        });
+
+    if present > 0 {
+        active as f32 / present as f32 * 100.0
+    } else {
+        0.0
+    }
+}
+
+/// Counts the days per heatmap level (0-4).
+fn level_histogram(days: &[ContributionDay]) -> [u32; 5] {
+    let mut histogram = [0; 5];
+    for day in days {
+        histogram[usize::from(day.level.min(4))] += 1;
+    }
+    histogram
+}
+
+/// Scores how regularly someone contributes, from 0 to 100.
+///
+/// Volume doesn't matter, only how often: the score is the mean of the
+/// share of active days and the share of active weeks, so one commit a
+/// day scores the same as fifty.
+///
+/// ```text
+/// Active days   120 / 365 = 32.9%
+/// Active weeks   40 /  53 = 75.5%
+///                          ───────
+/// Score         (32.9 + 75.5) / 2 = 54
+/// ```
+fn calculate_consistency_score(days: &[ContributionDay], weeks: &[ContributionWeek]) -> u8 {
+    if days.is_empty() || weeks.is_empty() {
+        return 0;
+    }
+
+    let active_days = days.iter().filter(|d| d.count > 0).count() as f32;
+    let active_weeks = weeks
+        .iter()
+        .filter(|week| week.days.iter().any(|d| d.count > 0))
+        .count() as f32;
+
+    let day_share = active_days / days.len() as f32;
+    let week_share = active_weeks / weeks.len() as f32;
+    ((day_share + week_share) / 2.0 * 100.0).round() as u8
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -532,6 +1301,7 @@ fn calculate_longest_streak(days: &[ContributionDay]) -> Streak {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::days;
 
     /// Tests basic HTML parsing functionality.
     #[test]
@@ -569,9 +1339,246 @@ mod tests {
             },
         ];
 
-        let stats = calculate_stats(&days);
+        let stats = calculate_stats(
+            &days,
+            NaiveDate::from_ymd_opt(2024, 1, 17).unwrap(),
+            &StreakRules::default(),
+        );
         assert_eq!(stats.total_contributions, 15);
         assert_eq!(stats.best_day.count, 10);
         assert_eq!(stats.best_day.date, "2024-01-16");
     }
+
+    /// Tests that the current streak is measured against the given "today".
+    #[test]
+    fn test_current_streak_uses_today() {
+        let days = days("2024-03-08", &[1; 3]);
+
+        let rules = StreakRules::default();
+        let today = NaiveDate::from_ymd_opt(2024, 3, 11).unwrap();
+        assert_eq!(calculate_current_streak(&days, today, &rules).count, 3);
+
+        // Two days later the streak is over
+        let today = NaiveDate::from_ymd_opt(2024, 3, 12).unwrap();
+        assert_eq!(calculate_current_streak(&days, today, &rules).count, 0);
+    }
+
+    /// Tests statistics "as of" a past date ignore later days.
+    #[test]
+    fn test_calculate_stats_as_of() {
+        let days = days("2024-03-01", &[2, 4, 0, 9]);
+
+        let stats = calculate_stats(
+            &days,
+            NaiveDate::from_ymd_opt(2024, 3, 3).unwrap(),
+            &StreakRules::default(),
+        );
+        assert_eq!(stats.total_contributions, 6);
+        assert_eq!(stats.current_streak.count, 2);
+        assert_eq!(stats.current_streak.end_date, "2024-03-02");
+
+        let timeline = calculate_streak_timeline(
+            &days,
+            NaiveDate::from_ymd_opt(2024, 3, 2).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 5).unwrap(),
+            &StreakRules::default(),
+        );
+        let current: Vec<u32> = timeline.iter().map(|p| p.current_streak).collect();
+        assert_eq!(current, vec![2, 2, 1, 1]);
+        assert_eq!(timeline.last().unwrap().longest_streak, 2);
+    }
+
+    /// Tests that rest days neither extend nor break relaxed streaks.
+    #[test]
+    fn test_rest_day_streaks() {
+        // Thu 2024-03-07 .. Mon 2024-03-11, weekend without contributions
+        let days = days("2024-03-07", &[2, 4, 0, 0, 3]);
+
+        let rules = StreakRules {
+            rest_days: vec![0, 6],
+            ..StreakRules::default()
+        };
+        let today = NaiveDate::from_ymd_opt(2024, 3, 11).unwrap();
+        let stats = calculate_stats(&days, today, &rules);
+
+        assert_eq!(stats.current_streak.count, 1);
+        assert_eq!(stats.longest_streak.count, 2);
+        assert_eq!(stats.relaxed_current_streak.count, 3);
+        assert_eq!(stats.relaxed_current_streak.start_date, "2024-03-07");
+        assert_eq!(stats.relaxed_longest_streak.count, 3);
+    }
+
+    /// Tests that days below the minimum daily threshold break streaks.
+    #[test]
+    fn test_streak_threshold() {
+        let days = days("2024-03-07", &[5, 1, 3, 4]);
+
+        let rules = StreakRules {
+            min_daily: 3,
+            ..StreakRules::default()
+        };
+        let today = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let stats = calculate_stats(&days, today, &rules);
+
+        assert_eq!(stats.streak_threshold, 3);
+        assert_eq!(stats.current_streak.count, 2);
+        assert_eq!(stats.current_streak.start_date, "2024-03-09");
+        assert_eq!(stats.longest_streak.count, 2);
+    }
+
+    /// Tests weekly and monthly streaks with an open current week.
+    #[test]
+    fn test_weekly_and_monthly_streaks() {
+        // 2024-01-28 (Sun) .. 2024-02-24 (Sat): four weeks with 3, 6, 6, 1 contributions
+        let counts: Vec<u32> = (0..28)
+            .map(|i| match (i / 7, i % 7) {
+                (0, 0) => 3,
+                (1, 0) | (2, 0) => 6,
+                (3, 0) => 1,
+                _ => 0,
+            })
+            .collect();
+        let days = days("2024-01-28", &counts);
+
+        let rules = StreakRules {
+            weekly_target: 5,
+            ..StreakRules::default()
+        };
+        let today = NaiveDate::from_ymd_opt(2024, 2, 24).unwrap();
+        let stats = calculate_stats(&days, today, &rules);
+
+        assert_eq!(stats.weekly_target, 5);
+        assert_eq!(stats.current_weekly_streak.count, 2);
+        assert_eq!(stats.current_weekly_streak.start_date, "2024-02-04");
+        assert_eq!(stats.current_weekly_streak.end_date, "2024-02-17");
+        assert_eq!(stats.longest_weekly_streak.count, 2);
+
+        // January and February both had activity
+        assert_eq!(stats.current_monthly_streak.count, 2);
+        assert_eq!(stats.current_monthly_streak.start_date, "2024-01-28");
+        assert_eq!(stats.longest_monthly_streak.count, 2);
+    }
+
+    /// Tests rolling averages, week-over-week change and the trend.
+    #[test]
+    fn test_rolling_averages_and_trend() {
+        // 30 days: 1 contribution per day, then 3 per day in the last week
+        let counts: Vec<u32> = (0..30).map(|i| if i >= 23 { 3 } else { 1 }).collect();
+        let days = days("2024-03-01", &counts);
+
+        let as_of = NaiveDate::from_ymd_opt(2024, 3, 30).unwrap();
+        let stats = calculate_stats(&days, as_of, &StreakRules::default());
+
+        assert_eq!(stats.rolling_averages.last_7_days, 3.0);
+        assert_eq!(stats.rolling_averages.last_90_days, 44.0 / 30.0);
+        assert_eq!(stats.week_over_week.current, 21);
+        assert_eq!(stats.week_over_week.previous, 7);
+        assert_eq!(stats.week_over_week.change_percent, Some(200.0));
+        assert_eq!(stats.month_over_month.change_percent, None);
+        assert_eq!(stats.trend, Trend::Up);
+    }
+
+    /// Tests median, deviation, active-day shares, level histogram and consistency.
+    #[test]
+    fn test_distribution_metrics() {
+        // Sun 2024-03-03 .. Sun 2024-03-10
+        let days = days("2024-03-03", &[1, 2, 0, 4, 0, 6, 3, 0]);
+
+        let as_of = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let stats = calculate_stats(&days, as_of, &StreakRules::default());
+
+        assert_eq!(stats.median_per_day, 1.5);
+        assert!((stats.std_deviation - 4.25f32.sqrt()).abs() < 0.001);
+        assert_eq!(stats.active_days.last_30_days, 62.5);
+        assert_eq!(stats.active_days.last_365_days, 62.5);
+        assert_eq!(stats.level_histogram, [3, 1, 1, 1, 2]);
+
+        // 5 of 8 days and 1 of 2 weeks were active
+        assert_eq!(stats.consistency_score, 56);
+    }
+
+    /// Tests that range stats only cover the visible heatmap weeks.
+    #[test]
+    fn test_range_stats() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let days = days("2024-01-01", &[1; 60]);
+        let today = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+
+        // 30 days before Thu Feb 29 is Tue Jan 30; its week starts on Sun Jan 28
+        let (range_start, range_end) = resolve_range_preset(RangePreset::Last30Days, today, start);
+        assert_eq!(range_start, NaiveDate::from_ymd_opt(2024, 1, 28).unwrap());
+        assert_eq!(range_end, today);
+
+        let range = calculate_range_stats(
+            &days,
+            range_start,
+            range_end,
+            today,
+            &StreakRules::default(),
+        );
+        assert_eq!(range.stats.total_contributions, 33);
+        assert_eq!(range.stats.longest_streak.start_date, "2024-01-28");
+        assert_eq!(range.weeks.len(), 5);
+        assert_eq!(range.stats.reported_total, None);
+
+        // The year view starts at the first cached day
+        assert_eq!(
+            resolve_range_preset(RangePreset::Year, today, start),
+            (start, today)
+        );
+    }
+
+    /// Tests parsing the calendar header total and flagging mismatches.
+    #[test]
+    fn test_reported_total() {
+        let html = r#"
+            <h2 id="js-contribution-activity-description" class="f4 text-normal mb-2">
+              1,234
+              contributions
+                in the last year
+            </h2>
+            <td data-date="2024-01-15" data-level="3"></td>
+        "#;
+
+        assert_eq!(parse_reported_total(html), Some(1234));
+        assert_eq!(parse_reported_total("<td></td>"), None);
+
+        let days = parse_contribution_html(html).unwrap();
+        let mut stats = calculate_stats(
+            &days,
+            NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+            &StreakRules::default(),
+        );
+        reconcile_reported_total(&mut stats, parse_reported_total(html));
+        assert_eq!(stats.reported_total, Some(1234));
+        assert!(stats.total_is_estimate);
+    }
+
+    /// Tests detection of private contribution markers.
+    #[test]
+    fn test_parse_private_contributions() {
+        // The summary may be repeated; the count is taken once
+        let html = r#"
+            <span>1,200 contributions in private repositories</span>
+            <span>1,200 contributions in private repositories</span>
+        "#;
+        let private = parse_private_contributions(html);
+        assert!(private.included);
+        assert_eq!(private.count, Some(1200));
+
+        let private =
+            parse_private_contributions(r#"<span class="text-bold">Private contributions</span>"#);
+        assert!(private.included);
+        assert_eq!(private.count, None);
+
+        // Mentions in free text are not markers
+        let private = parse_private_contributions(
+            r#"<div class="bio">I turned on private contributions last year</div>"#,
+        );
+        assert!(!private.included);
+
+        let private = parse_private_contributions(r#"<td data-date="2024-01-15"></td>"#);
+        assert!(!private.included);
+        assert_eq!(private.count, None);
+    }
 }
@@ -0,0 +1,250 @@
+//! # GitHub Profile Fetcher
+//!
+//! This module fetches public profile metadata from GitHub's REST API
+//! to enrich the `UserInfo` shown in the popup header.
+//!
+//! ```text
+//! URL: https://api.github.com/users/{username}
+//!
+//! Response (200 OK):
+//! ┌─────────────────────────────────────────────────────┐
+//! │ {                                                   │
+//! │   "login": "octocat",                               │
+//! │   "name": "The Octocat",                            │
+//! │   "avatar_url": "https://avatars.github...?v=4",    │
+//! │   "bio": null,                                      │
+//! │   "company": "@github",                             │
+//! │   "followers": 9000,                                │
+//! │   "following": 9,                                   │
+//! │   "created_at": "2011-01-25T18:44:36Z"              │
+//! │ }                                                   │
+//! └─────────────────────────────────────────────────────┘
+//! ```
+//!
+//! Unauthenticated API requests are rate limited (60 per hour), so the
+//! last good profile is kept in memory and only refetched once it is
+//! older than `PROFILE_MAX_AGE_SECS`. A failed fetch falls back to the
+//! stale profile, and only without one to a basic `UserInfo` built from
+//! the username alone.
+
+use crate::types::UserInfo;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// Avatar size requested for the popup header, in pixels.
+const AVATAR_SIZE: u32 = 80;
+
+/// How long a fetched profile is reused before it is requested again (6 hours).
+const PROFILE_MAX_AGE_SECS: i64 = 6 * 60 * 60;
+
+/// Last good profile per user, as `(fetched_at, profile)`.
+///
+/// `fetched_at` is a Unix timestamp in seconds.
+static PROFILE_CACHE: Lazy<RwLock<HashMap<String, (i64, GitHubProfile)>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Subset of the REST `GET /users/{username}` response that we use.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubProfile {
+    /// Display name ("The Octocat")
+    pub name: Option<String>,
+
+    /// Canonical avatar URL on avatars.githubusercontent.com
+    pub avatar_url: Option<String>,
+
+    /// Profile bio
+    pub bio: Option<String>,
+
+    /// Company field (often an "@org" mention)
+    pub company: Option<String>,
+
+    /// Number of followers
+    pub followers: Option<u32>,
+
+    /// Number of accounts the user follows
+    pub following: Option<u32>,
+
+    /// Account creation timestamp (ISO 8601)
+    pub created_at: Option<String>,
+}
+
+/// Fetches public profile metadata for a user.
+///
+/// # Arguments
+///
+/// * `client` - HTTP client with the Git Streaks User-Agent
+/// * `username` - A normalized GitHub login
+///
+/// # Returns
+///
+/// * `Ok(GitHubProfile)` - The parsed profile
+/// * `Err(String)` - Error if the request fails, is rate limited or can't be parsed
+pub async fn fetch_profile(
+    client: &reqwest::Client,
+    username: &str,
+) -> Result<GitHubProfile, String> {
+    let url = format!("https://api.github.com/users/{}", username);
+
+    let response = client
+        .get(&url)
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch profile: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("GitHub API returned status {}", response.status()));
+    }
+
+    let body = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read profile: {}", e))?;
+
+    serde_json::from_str(&body).map_err(|e| format!("Failed to parse profile: {}", e))
+}
+
+/// Returns the user's profile, reusing the last good one while it's fresh.
+///
+/// # Arguments
+///
+/// * `client` - HTTP client with the Git Streaks User-Agent
+/// * `username` - A normalized GitHub login
+///
+/// # Returns
+///
+/// The cached or freshly fetched profile, a stale one if the request
+/// fails, or `None` if the profile was never fetched successfully
+pub async fn cached_profile(client: &reqwest::Client, username: &str) -> Option<GitHubProfile> {
+    let cached = PROFILE_CACHE
+        .read()
+        .ok()
+        .and_then(|cache| cache.get(username).cloned());
+
+    if let Some((fetched_at, profile)) = cached.as_ref() {
+        if chrono::Utc::now().timestamp() - fetched_at < PROFILE_MAX_AGE_SECS {
+            return Some(profile.clone());
+        }
+    }
+
+    match fetch_profile(client, username).await {
+        Ok(profile) => {
+            if let Ok(mut cache) = PROFILE_CACHE.write() {
+                cache.insert(
+                    username.to_string(),
+                    (chrono::Utc::now().timestamp(), profile.clone()),
+                );
+            }
+            Some(profile)
+        }
+        // Rate limited or offline: keep showing the last good profile
+        Err(_) => cached.map(|(_, profile)| profile),
+    }
+}
+
+/// Builds the `UserInfo` for the popup header.
+///
+/// Uses the profile metadata when available, otherwise falls back
+/// to GitHub's `https://github.com/{username}.png` avatar pattern.
+///
+/// # Arguments
+///
+/// * `username` - A normalized GitHub login
+/// * `profile` - Profile metadata, if it could be fetched
+///
+/// # Returns
+///
+/// The user information to display
+pub fn build_user_info(username: &str, profile: Option<GitHubProfile>) -> UserInfo {
+    let fallback_avatar = format!("https://github.com/{}.png?size={}", username, AVATAR_SIZE);
+
+    let Some(profile) = profile else {
+        return UserInfo {
+            username: username.to_string(),
+            avatar_url: fallback_avatar,
+            ..UserInfo::default()
+        };
+    };
+
+    // Canonical avatar URLs already carry a query string ("?v=4")
+    let avatar_url = profile
+        .avatar_url
+        .map(|url| {
+            let separator = if url.contains('?') { '&' } else { '?' };
+            format!("{}{}s={}", url, separator, AVATAR_SIZE)
+        })
+        .unwrap_or(fallback_avatar);
+
+    UserInfo {
+        username: username.to_string(),
+        avatar_url,
+        avatar_data_uri: None,
+        display_name: profile.name.filter(|name| !name.trim().is_empty()),
+        bio: profile.bio.filter(|bio| !bio.trim().is_empty()),
+        company: profile.company.filter(|company| !company.trim().is_empty()),
+        followers: profile.followers,
+        following: profile.following,
+        created_at: profile.created_at,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests building UserInfo from a REST API response.
+    #[test]
+    fn test_build_user_info_from_profile() {
+        let profile: GitHubProfile = serde_json::from_str(
+            r#"{
+                "login": "octocat",
+                "name": "The Octocat",
+                "avatar_url": "https://avatars.githubusercontent.com/u/583231?v=4",
+                "bio": "",
+                "company": "@github",
+                "followers": 9000,
+                "following": 9,
+                "created_at": "2011-01-25T18:44:36Z"
+            }"#,
+        )
+        .unwrap();
+
+        let user = build_user_info("octocat", Some(profile));
+        assert_eq!(
+            user.avatar_url,
+            "https://avatars.githubusercontent.com/u/583231?v=4&s=80"
+        );
+        assert_eq!(user.display_name.as_deref(), Some("The Octocat"));
+        assert_eq!(user.bio, None);
+        assert_eq!(user.followers, Some(9000));
+        assert_eq!(user.created_at.as_deref(), Some("2011-01-25T18:44:36Z"));
+    }
+
+    /// Tests that a fresh cached profile is served without a request.
+    #[tokio::test]
+    async fn test_cached_profile_is_reused() {
+        let profile: GitHubProfile = serde_json::from_str(r#"{ "name": "Cached Cat" }"#).unwrap();
+        PROFILE_CACHE.write().unwrap().insert(
+            "cached-cat".to_string(),
+            (chrono::Utc::now().timestamp(), profile),
+        );
+
+        let client = reqwest::Client::new();
+        let profile = cached_profile(&client, "cached-cat").await.unwrap();
+        assert_eq!(profile.name.as_deref(), Some("Cached Cat"));
+    }
+
+    /// Tests the fallback when the profile couldn't be fetched.
+    #[test]
+    fn test_build_user_info_without_profile() {
+        let user = build_user_info("octocat", None);
+        assert_eq!(user.avatar_url, "https://github.com/octocat.png?size=80");
+        assert_eq!(user.display_name, None);
+    }
+}
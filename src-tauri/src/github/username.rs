@@ -0,0 +1,200 @@
+//! # GitHub Username Validation
+//!
+//! This module checks usernames against GitHub's login rules before
+//! they are saved or formatted into a request URL.
+//!
+//! ## Login Rules
+//!
+//! ```text
+//! ✓ 1-39 characters
+//! ✓ Letters, digits and hyphens only
+//! ```
+//!
+//! New logins can't start or end with a hyphen or contain "--", but some
+//! older accounts do, so hyphen positions aren't checked; `user_exists`
+//! decides whether such a login is real.
+//!
+//! Logins are case-insensitive on GitHub, so input is trimmed, stripped
+//! of a leading `@` and lowercased before validation.
+
+use crate::github::fetcher::build_client;
+use crate::types::UsernameValidation;
+
+/// Maximum length of a GitHub login.
+const MAX_USERNAME_LENGTH: usize = 39;
+
+/// Normalizes a username and checks it against GitHub's login rules.
+///
+/// # Arguments
+///
+/// * `input` - Username as typed by the user (e.g. "  @Octocat ")
+///
+/// # Returns
+///
+/// * `Ok(String)` - The normalized login (e.g. "octocat")
+/// * `Err(String)` - Description of the rule that was broken
+///
+/// # Example
+///
+/// ```rust,ignore
+/// assert_eq!(normalize_username(" @Octocat ")?, "octocat");
+/// assert!(normalize_username("../etc").is_err());
+/// ```
+pub fn normalize_username(input: &str) -> Result<String, String> {
+    let username = input.trim();
+    let username = username.strip_prefix('@').unwrap_or(username);
+    let username = username.to_ascii_lowercase();
+
+    if username.is_empty() {
+        return Err("Username cannot be empty".to_string());
+    }
+
+    if username.len() > MAX_USERNAME_LENGTH {
+        return Err(format!(
+            "Username cannot be longer than {} characters",
+            MAX_USERNAME_LENGTH
+        ));
+    }
+
+    if !username
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-')
+    {
+        return Err("Username may only contain letters, digits and hyphens".to_string());
+    }
+
+    Ok(username)
+}
+
+/// Checks whether a GitHub account exists.
+///
+/// Sends a `HEAD` request to the profile page, which is much lighter
+/// than fetching the contribution calendar.
+///
+/// # Arguments
+///
+/// * `username` - A normalized GitHub login
+///
+/// # Returns
+///
+/// * `Ok(true)` - The profile exists
+/// * `Ok(false)` - GitHub returned 404
+/// * `Err(String)` - The request failed or returned an unexpected status
+pub async fn user_exists(username: &str) -> Result<bool, String> {
+    let url = format!("https://github.com/{}", username);
+
+    let response = build_client()?
+        .head(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to check username: {}", e))?;
+
+    match response.status() {
+        status if status.is_success() => Ok(true),
+        reqwest::StatusCode::NOT_FOUND => Ok(false),
+        status => Err(format!("GitHub returned status {}", status)),
+    }
+}
+
+/// Validates a username, optionally checking that the account exists.
+///
+/// ## Validation Flow
+///
+/// ```text
+/// "  @Octocat "
+///       │
+///       ▼
+/// ┌─────────────────────┐
+/// │ normalize_username  │──▶ invalid ──▶ { valid: false, error }
+/// └─────────────────────┘
+///       │ "octocat"
+///       ▼
+/// ┌─────────────────────┐
+/// │ user_exists         │──▶ (only if check_exists)
+/// └─────────────────────┘
+///       │
+///       ▼
+/// { username: "octocat", valid: true, exists: Some(true) }
+/// ```
+///
+/// # Arguments
+///
+/// * `input` - Username as typed by the user
+/// * `check_exists` - Whether to ask GitHub if the account exists
+///
+/// # Returns
+///
+/// The validation result, suitable for display in the settings form
+pub async fn validate_username(input: &str, check_exists: bool) -> UsernameValidation {
+    let username = match normalize_username(input) {
+        Ok(username) => username,
+        Err(error) => {
+            return UsernameValidation {
+                username: input.trim().to_string(),
+                valid: false,
+                exists: None,
+                error: Some(error),
+            }
+        }
+    };
+
+    if !check_exists {
+        return UsernameValidation {
+            username,
+            valid: true,
+            exists: None,
+            error: None,
+        };
+    }
+
+    match user_exists(&username).await {
+        Ok(true) => UsernameValidation {
+            username,
+            valid: true,
+            exists: Some(true),
+            error: None,
+        },
+        Ok(false) => UsernameValidation {
+            error: Some(format!("GitHub user \"{}\" does not exist", username)),
+            username,
+            valid: true,
+            exists: Some(false),
+        },
+        // Network problems shouldn't block saving a well-formed username
+        Err(error) => UsernameValidation {
+            username,
+            valid: true,
+            exists: None,
+            error: Some(error),
+        },
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests that usernames are trimmed, stripped of "@" and lowercased.
+    #[test]
+    fn test_normalize_username() {
+        assert_eq!(normalize_username("  @Octocat ").unwrap(), "octocat");
+        assert_eq!(normalize_username("some-user-42").unwrap(), "some-user-42");
+
+        // Older accounts may end with a hyphen or contain "--"
+        assert_eq!(normalize_username("octocat-").unwrap(), "octocat-");
+        assert_eq!(normalize_username("octo--cat").unwrap(), "octo--cat");
+    }
+
+    /// Tests that usernames breaking GitHub's login rules are rejected.
+    #[test]
+    fn test_normalize_username_rejects_invalid() {
+        assert!(normalize_username("").is_err());
+        assert!(normalize_username("../etc").is_err());
+        assert!(normalize_username("two words").is_err());
+        assert!(normalize_username(&"a".repeat(40)).is_err());
+    }
+}
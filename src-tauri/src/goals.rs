@@ -0,0 +1,250 @@
+//! # Contribution Goals
+//!
+//! Personal targets like "5 contributions a day" or "150 per month".
+//! Goals are stored in the settings store; this module computes their
+//! progress from the cached contribution days.
+//!
+//! ## Goal Periods
+//!
+//! ```text
+//! Period   Current period (today = Wed 2024-03-13)
+//! ───────────────────────────────────────────────────
+//! day      2024-03-13
+//! week     2024-03-10 (Sun) ..= 2024-03-16 (Sat)
+//! month    2024-03-01 ..= 2024-03-31
+//! ```
+//!
+//! A goal may be limited to certain weekdays. For weekly and monthly
+//! goals only contributions on those weekdays count; for daily goals
+//! the other weekdays are skipped entirely (they neither hit nor miss).
+//!
+//! ## Hit Streaks
+//!
+//! ```text
+//! Goal: 5 per day
+//!
+//! Day     Mon  Tue  Wed  Thu  Fri (today)
+//! Count    6    2    5    7    1
+//! Met      ✓    ✗    ✓    ✓    …   ◀── Current hit streak = 2
+//! ```
+//!
+//! Like the daily streak, the current period gets grace: it isn't
+//! over yet, so missing the goal so far doesn't break the hit streak.
+
+use crate::types::{ContributionDay, Goal, GoalPeriod, GoalProgress};
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::HashMap;
+
+/// Returns the first and last date of the goal period containing `date`.
+pub fn period_bounds(period: GoalPeriod, date: NaiveDate) -> (NaiveDate, NaiveDate) {
+    match period {
+        GoalPeriod::Day => (date, date),
+        GoalPeriod::Week => {
+            let start = date - Duration::days(date.weekday().num_days_from_sunday() as i64);
+            (start, start + Duration::days(6))
+        }
+        GoalPeriod::Month => {
+            let start = date.with_day(1).unwrap_or(date);
+            let next_month = if start.month() == 12 {
+                NaiveDate::from_ymd_opt(start.year() + 1, 1, 1)
+            } else {
+                NaiveDate::from_ymd_opt(start.year(), start.month() + 1, 1)
+            };
+            let end = next_month.map(|d| d - Duration::days(1)).unwrap_or(start);
+            (start, end)
+        }
+    }
+}
+
+/// Returns whether contributions on `date` count towards a goal.
+fn counts_on(goal: &Goal, date: NaiveDate) -> bool {
+    goal.weekdays.is_empty()
+        || goal
+            .weekdays
+            .contains(&(date.weekday().num_days_from_sunday() as u8))
+}
+
+/// Sums the contributions that count towards a goal in `start..=end`.
+fn period_total(
+    goal: &Goal,
+    counts: &HashMap<NaiveDate, u32>,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> u32 {
+    start
+        .iter_days()
+        .take_while(|date| *date <= end)
+        .filter(|date| counts_on(goal, *date))
+        .map(|date| counts.get(&date).copied().unwrap_or(0))
+        .sum()
+}
+
+/// Calculates the progress of a goal.
+///
+/// # Arguments
+///
+/// * `goal` - The goal definition
+/// * `days` - Slice of contribution days (sorted chronologically)
+/// * `today` - Today's date in the user's timezone
+///
+/// # Returns
+///
+/// Progress in the current period and the goal's hit streaks
+pub fn calculate_goal_progress(
+    goal: &Goal,
+    days: &[ContributionDay],
+    today: NaiveDate,
+) -> GoalProgress {
+    let counts: HashMap<NaiveDate, u32> = days
+        .iter()
+        .filter_map(|day| {
+            NaiveDate::parse_from_str(&day.date, "%Y-%m-%d")
+                .ok()
+                .map(|date| (date, day.count))
+        })
+        .collect();
+
+    let (period_start, period_end) = period_bounds(goal.period, today);
+    let current = period_total(goal, &counts, period_start, today.min(period_end));
+    let target = goal.target.max(1);
+
+    // Walk every period from the first cached day up to the current one
+    let mut longest_hit_streak = 0;
+    let mut run = 0;
+    let mut previous_run = 0;
+    if let Some(first) = counts.keys().min() {
+        let mut start = period_bounds(goal.period, *first).0;
+        while start <= period_start {
+            let (_, end) = period_bounds(goal.period, start);
+            let next = end + Duration::days(1);
+
+            // Daily goals skip the weekdays they don't apply to
+            if goal.period == GoalPeriod::Day && !counts_on(goal, start) {
+                start = next;
+                continue;
+            }
+
+            previous_run = run;
+            run = if period_total(goal, &counts, start, end.min(today)) >= target {
+                run + 1
+            } else {
+                0
+            };
+            longest_hit_streak = longest_hit_streak.max(run);
+            start = next;
+        }
+    }
+
+    let met = current >= target;
+    let applies_today = goal.period != GoalPeriod::Day || counts_on(goal, today);
+
+    GoalProgress {
+        goal: goal.clone(),
+        period_start: period_start.format("%Y-%m-%d").to_string(),
+        period_end: period_end.format("%Y-%m-%d").to_string(),
+        current,
+        met,
+        percent: (current as f32 / target as f32 * 100.0).min(100.0),
+        // The current period is still open, so a miss so far doesn't count yet
+        current_hit_streak: if met || !applies_today {
+            run
+        } else {
+            previous_run
+        },
+        longest_hit_streak,
+    }
+}
+
+/// Validates goal definitions before they are saved.
+///
+/// # Returns
+///
+/// * `Ok(())` - All goals are valid
+/// * `Err(String)` - Description of the first invalid goal
+pub fn validate_goals(goals: &[Goal]) -> Result<(), String> {
+    let mut ids: Vec<&str> = Vec::new();
+
+    for goal in goals {
+        if goal.id.trim().is_empty() {
+            return Err("Goal id must not be empty".to_string());
+        }
+        if ids.contains(&goal.id.as_str()) {
+            return Err(format!("Duplicate goal id \"{}\"", goal.id));
+        }
+        if goal.target == 0 {
+            return Err(format!(
+                "Goal \"{}\" needs a target of at least 1",
+                goal.name
+            ));
+        }
+        if goal.weekdays.iter().any(|day| *day > 6) {
+            return Err(format!(
+                "Goal \"{}\" has an invalid weekday (use 0 = Sunday to 6 = Saturday)",
+                goal.name
+            ));
+        }
+        ids.push(&goal.id);
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::days;
+
+    fn goal(period: GoalPeriod, target: u32) -> Goal {
+        Goal {
+            id: "goal".to_string(),
+            name: "Goal".to_string(),
+            period,
+            target,
+            weekdays: Vec::new(),
+        }
+    }
+
+    /// Tests daily goal progress and hit streaks, matching the module diagram.
+    #[test]
+    fn test_daily_goal_progress() {
+        // Mon 2024-03-11 .. Fri 2024-03-15
+        let days = days("2024-03-11", &[6, 2, 5, 7, 1]);
+        let today = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+
+        let progress = calculate_goal_progress(&goal(GoalPeriod::Day, 5), &days, today);
+        assert_eq!(progress.current, 1);
+        assert!(!progress.met);
+        assert_eq!(progress.percent, 20.0);
+        assert_eq!(progress.current_hit_streak, 2);
+        assert_eq!(progress.longest_hit_streak, 2);
+    }
+
+    /// Tests monthly goals and weekday-limited goals.
+    #[test]
+    fn test_monthly_and_weekday_goals() {
+        let days = days("2024-02-25", &[10; 19]); // Sun Feb 25 .. Wed Mar 14
+        let today = NaiveDate::from_ymd_opt(2024, 3, 14).unwrap();
+
+        let monthly = calculate_goal_progress(&goal(GoalPeriod::Month, 100), &days, today);
+        assert_eq!(monthly.period_start, "2024-03-01");
+        assert_eq!(monthly.period_end, "2024-03-31");
+        assert_eq!(monthly.current, 140);
+        assert!(monthly.met);
+        assert_eq!(monthly.current_hit_streak, 1); // February only had 50
+
+        // Weekends only: the current week (Mar 10-16) has one Sunday so far
+        let weekends = Goal {
+            weekdays: vec![0, 6],
+            ..goal(GoalPeriod::Week, 20)
+        };
+        let progress = calculate_goal_progress(&weekends, &days, today);
+        assert_eq!(progress.current, 10);
+        assert_eq!(progress.current_hit_streak, 2);
+
+        assert!(validate_goals(&[goal(GoalPeriod::Day, 0)]).is_err());
+    }
+}
@@ -0,0 +1,373 @@
+//! # Data Import
+//!
+//! Reads day counts from CSV or `git log` output, for backfilling
+//! history or moving to a new machine. Imported days are merged with
+//! the known days using a `MergePolicy`.
+//!
+//! Each import is kept as an `ImportBatch` and replayed in order on
+//! top of GitHub's days. Only days inside GitHub's calendar window are
+//! merged, so the heatmap keeps its one-year width.
+//!
+//! ## Accepted Input
+//!
+//! ```text
+//! CSV                              git log --format=%ad --date=short
+//! ──────────────────────────       ─────────────────────────────────
+//! date,count        ◀── optional   2024-03-02   ◀── one line per commit
+//! 2024-03-01,3          header     2024-03-02
+//! 2024-03-02,1                     2024-03-01
+//! ```
+//!
+//! A CSV written by `export_contributions` also works: only its
+//! `date,count,level` section is read. Repeated dates are added up.
+//!
+//! ## Merging
+//!
+//! ```text
+//! known:     03-01: 2   03-02: 5
+//! imported:             03-02: 3   03-04: 1
+//! ──────────────────────────────────────────────────────────
+//! max:       03-01: 2   03-02: 5   03-03: 0   03-04: 1
+//! sum:       03-01: 2   03-02: 8   03-03: 0   03-04: 1
+//! replace:   03-01: 2   03-02: 3   03-03: 0   03-04: 1
+//!                                  ▲
+//!                                  └── gaps are filled before the days
+//!                                      replace the cached series
+//! ```
+
+use crate::types::{ContributionDay, ImportBatch, ImportFormat, MergePolicy};
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+
+/// Parses imported text into contribution days.
+///
+/// # Arguments
+///
+/// * `text` - File contents
+/// * `format` - CSV or `git log` output
+///
+/// # Returns
+///
+/// * `Ok(Vec<ContributionDay>)` - One day per distinct date, chronologically
+///   (levels are left at 0)
+/// * `Err(String)` - Error naming the first invalid line, or if no days were found
+pub fn parse_import(text: &str, format: ImportFormat) -> Result<Vec<ContributionDay>, String> {
+    let counts = match format {
+        ImportFormat::Csv => parse_csv(text)?,
+        ImportFormat::GitLog => parse_git_log(text)?,
+    };
+    if counts.is_empty() {
+        return Err("No contribution days found".to_string());
+    }
+
+    Ok(counts
+        .into_iter()
+        .map(|(date, count)| to_day(date, count))
+        .collect())
+}
+
+/// Merges imported days into known days.
+///
+/// Days only present on one side are kept as they are. Levels are
+/// reset to 0; the caller assigns them for the merged series.
+///
+/// # Arguments
+///
+/// * `base` - Known days (e.g. from GitHub)
+/// * `imported` - Days from `parse_import`
+/// * `policy` - How to combine counts for dates on both sides
+///
+/// # Returns
+///
+/// Every date of both sides, chronologically
+pub fn merge_days(
+    base: &[ContributionDay],
+    imported: &[ContributionDay],
+    policy: MergePolicy,
+) -> Vec<ContributionDay> {
+    let mut counts = count_map(base);
+
+    for day in imported {
+        let Some(date) = parse_date(&day.date) else {
+            continue;
+        };
+        let count = counts.entry(date).or_insert(0);
+        *count = match policy {
+            MergePolicy::Max => (*count).max(day.count),
+            MergePolicy::Sum => count.saturating_add(day.count),
+            MergePolicy::Replace => day.count,
+        };
+    }
+
+    counts
+        .into_iter()
+        .map(|(date, count)| to_day(date, count))
+        .collect()
+}
+
+/// Replays import batches on top of the known days.
+///
+/// Batches are merged in order, each with its own policy. Imported
+/// days before the first known day or after `today` are dropped, so
+/// the result covers the same calendar window as `base` (the window
+/// moves on, so days stored earlier can fall out of it).
+///
+/// # Arguments
+///
+/// * `base` - Known days (e.g. from GitHub), chronologically
+/// * `batches` - Import batches in import order
+/// * `today` - Today's date in the user's timezone
+///
+/// # Returns
+///
+/// One day per date from the first known day, chronologically
+/// (levels are left at 0)
+pub fn apply_batches(
+    base: &[ContributionDay],
+    batches: &[ImportBatch],
+    today: NaiveDate,
+) -> Vec<ContributionDay> {
+    let Some(first) = base.first().and_then(|day| parse_date(&day.date)) else {
+        return Vec::new();
+    };
+
+    let mut merged = batches.iter().fold(base.to_vec(), |days, batch| {
+        merge_days(&days, &batch.days, batch.policy)
+    });
+    retain_window(&mut merged, first, today);
+
+    fill_gaps(&merged)
+}
+
+/// Drops days outside a calendar window.
+///
+/// # Arguments
+///
+/// * `days` - Days to filter in place
+/// * `first` - First day of the window (GitHub's first calendar day)
+/// * `today` - Last day of the window
+///
+/// # Returns
+///
+/// Number of days dropped
+pub fn retain_window(days: &mut Vec<ContributionDay>, first: NaiveDate, today: NaiveDate) -> u32 {
+    let before = days.len();
+    days.retain(|day| parse_date(&day.date).is_some_and(|date| date >= first && date <= today));
+    (before - days.len()) as u32
+}
+
+/// Fills missing dates with zero days.
+///
+/// Weeks and streaks expect one day per date, so merged days are
+/// filled before they replace the cached series.
+///
+/// # Returns
+///
+/// Every date from the first to the last day, chronologically
+pub fn fill_gaps(days: &[ContributionDay]) -> Vec<ContributionDay> {
+    let counts = count_map(days);
+    let (Some(first), Some(last)) = (
+        counts.keys().next().copied(),
+        counts.keys().next_back().copied(),
+    ) else {
+        return Vec::new();
+    };
+
+    first
+        .iter_days()
+        .take_while(|date| *date <= last)
+        .map(|date| to_day(date, counts.get(&date).copied().unwrap_or(0)))
+        .collect()
+}
+
+/// Collects the counts by date, skipping invalid dates.
+fn count_map(days: &[ContributionDay]) -> BTreeMap<NaiveDate, u32> {
+    days.iter()
+        .filter_map(|day| Some((parse_date(&day.date)?, day.count)))
+        .collect()
+}
+
+/// Creates a day with level 0.
+fn to_day(date: NaiveDate, count: u32) -> ContributionDay {
+    ContributionDay {
+        date: date.format("%Y-%m-%d").to_string(),
+        count,
+        level: 0,
+    }
+}
+
+/// Parses `date,count` rows into per-date totals.
+///
+/// A header row (first field "date") is skipped; rows then run until
+/// the next blank line, which ends the days section of an export.
+fn parse_csv(text: &str) -> Result<BTreeMap<NaiveDate, u32>, String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let header = lines
+        .iter()
+        .position(|line| field(line.split(',').next()).eq_ignore_ascii_case("date"));
+    let start = header.map_or(0, |index| index + 1);
+
+    let mut counts: BTreeMap<NaiveDate, u32> = BTreeMap::new();
+    for (index, line) in lines.iter().enumerate().skip(start) {
+        if line.trim().is_empty() {
+            if header.is_some() {
+                break;
+            }
+            continue;
+        }
+
+        let mut fields = line.split(',');
+        let date = field(fields.next());
+        let date = parse_date(date)
+            .ok_or_else(|| format!("Line {}: invalid date \"{}\"", index + 1, date))?;
+        let count = field(fields.next());
+        let count: u32 = count
+            .parse()
+            .map_err(|_| format!("Line {}: invalid count \"{}\"", index + 1, count))?;
+
+        let total = counts.entry(date).or_insert(0);
+        *total = total.saturating_add(count);
+    }
+
+    Ok(counts)
+}
+
+/// Counts commits per date in `git log` output.
+///
+/// Only the first word of each line is read, so `--date=iso` output
+/// works as well as `--date=short`.
+fn parse_git_log(text: &str) -> Result<BTreeMap<NaiveDate, u32>, String> {
+    let mut counts: BTreeMap<NaiveDate, u32> = BTreeMap::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let Some(word) = line.split_whitespace().next() else {
+            continue;
+        };
+        let date = parse_date(word)
+            .ok_or_else(|| format!("Line {}: invalid date \"{}\"", index + 1, word))?;
+        let total = counts.entry(date).or_insert(0);
+        *total = total.saturating_add(1);
+    }
+
+    Ok(counts)
+}
+
+/// Trims whitespace and surrounding quotes from a CSV field.
+fn field(value: Option<&str>) -> &str {
+    value.unwrap_or("").trim().trim_matches('"')
+}
+
+/// Parses a YYYY-MM-DD date.
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns `(date, count)` pairs for comparisons.
+    fn counts(days: &[ContributionDay]) -> Vec<(&str, u32)> {
+        days.iter()
+            .map(|day| (day.date.as_str(), day.count))
+            .collect()
+    }
+
+    /// Tests CSV and git log parsing, and every merge policy.
+    #[test]
+    fn test_import_and_merge() {
+        let csv = parse_import(
+            "2024-03-02,3\n\n2024-03-01, 2\n2024-03-02,1\n",
+            ImportFormat::Csv,
+        )
+        .unwrap();
+        assert_eq!(counts(&csv), vec![("2024-03-01", 2), ("2024-03-02", 4)]);
+
+        // Exported CSV: only the days section is read
+        let export = "schema_version,1\nusername,octocat\n\ndate,count,level\n\
+                      2024-03-04,1,1\n\nstat,value\ntotal_contributions,1\n";
+        let exported = parse_import(export, ImportFormat::Csv).unwrap();
+        assert_eq!(counts(&exported), vec![("2024-03-04", 1)]);
+
+        let error = parse_import("date,count\n2024-03-01,x\n", ImportFormat::Csv).unwrap_err();
+        assert_eq!(error, "Line 2: invalid count \"x\"");
+        assert!(parse_import("date,count\n", ImportFormat::Csv).is_err());
+
+        let log = "2024-03-02\n2024-03-02 10:00:00 +0100\n\n2024-03-04\n";
+        let commits = parse_import(log, ImportFormat::GitLog).unwrap();
+        assert_eq!(counts(&commits), vec![("2024-03-02", 2), ("2024-03-04", 1)]);
+        assert!(parse_import("commit abc\n", ImportFormat::GitLog).is_err());
+
+        let base = vec![
+            ContributionDay {
+                date: "2024-03-01".to_string(),
+                count: 2,
+                level: 1,
+            },
+            ContributionDay {
+                date: "2024-03-02".to_string(),
+                count: 5,
+                level: 3,
+            },
+        ];
+        let expected =
+            |merged: u32| vec![("2024-03-01", 2), ("2024-03-02", merged), ("2024-03-04", 1)];
+        let max = merge_days(&base, &commits, MergePolicy::Max);
+        assert_eq!(counts(&max), expected(5));
+        let sum = merge_days(&base, &commits, MergePolicy::Sum);
+        assert_eq!(counts(&sum), expected(7));
+
+        // Huge counts saturate instead of overflowing
+        let huge = parse_import("2024-03-02,4294967295\n2024-03-02,1\n", ImportFormat::Csv);
+        assert_eq!(counts(&huge.unwrap()), vec![("2024-03-02", u32::MAX)]);
+        let huge = parse_import("2024-03-02,4294967295\n", ImportFormat::Csv).unwrap();
+        let summed = merge_days(&base, &huge, MergePolicy::Sum);
+        assert_eq!(counts(&summed)[1], ("2024-03-02", u32::MAX));
+
+        let replace = merge_days(&base, &commits, MergePolicy::Replace);
+        assert_eq!(counts(&replace), expected(2));
+
+        let filled = fill_gaps(&replace);
+        assert_eq!(filled.len(), 4);
+        assert_eq!(counts(&filled)[2], ("2024-03-03", 0));
+
+        // Batches keep their own policy: a later "replace" doesn't turn an
+        // earlier "sum" into a replace, and history before the window is dropped
+        let batches = [
+            ImportBatch {
+                policy: MergePolicy::Sum,
+                days: parse_import("2024-03-01,1\n", ImportFormat::Csv).unwrap(),
+            },
+            ImportBatch {
+                policy: MergePolicy::Replace,
+                days: parse_import("2020-01-01,9\n2024-03-02,1\n", ImportFormat::Csv).unwrap(),
+            },
+        ];
+        let today = NaiveDate::from_ymd_opt(2024, 3, 3).unwrap();
+        let replayed = apply_batches(&base, &batches, today);
+        assert_eq!(
+            counts(&replayed),
+            vec![("2024-03-01", 3), ("2024-03-02", 1)]
+        );
+    }
+
+    /// Tests that days before the calendar window or after today are
+    /// skipped and counted.
+    #[test]
+    fn test_retain_window() {
+        let mut days = parse_import(
+            "2023-12-31,4\n2024-03-01,1\n2024-03-03,2\n2024-03-04,5\n",
+            ImportFormat::Csv,
+        )
+        .unwrap();
+        let first = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let today = NaiveDate::from_ymd_opt(2024, 3, 3).unwrap();
+
+        assert_eq!(retain_window(&mut days, first, today), 2);
+        assert_eq!(counts(&days), vec![("2024-03-01", 1), ("2024-03-03", 2)]);
+    }
+}
@@ -24,6 +24,14 @@
 //! │  │  - refresh_contributions  - save_settings                       │  │
 //! │  │  - get_cached_contributions - hide_window                       │  │
 //! │  │  - get_autostart_enabled  - set_autostart_enabled               │  │
+//! │  │  - validate_username      - get_stats_as_of                     │  │
+//! │  │  - get_streak_timeline    - get_streak_history                  │  │
+//! │  │  - get_top_streaks        - get_range_stats                     │  │
+//! │  │  - get_goals / save_goals - get_goal_progress                   │  │
+//! │  │  - get_achievements       - get_projections                     │  │
+//! │  │  - export_contributions   - export_heatmap_svg                  │  │
+//! │  │  - export_stats_card      - export_streak_badge                 │  │
+//! │  │  - import_contributions                                         │  │
 //! │  └─────────────────────────────────────────────────────────────────┘  │
 //! │                               │                                        │
 //! │                               ▼                                        │
@@ -74,20 +82,43 @@
 //! └───────────────────┘
 //! ```
 
+mod achievements;
+mod clock;
+mod export;
 mod github;
+mod goals;
+mod import;
+mod projections;
+mod render;
+mod server;
+#[cfg(test)]
+mod test_support;
+mod timezone;
 mod types;
 
+use base64::Engine;
+use chrono::{Datelike, NaiveDate};
+use clock::{Clock, FixedClock, SystemClock};
 use once_cell::sync::Lazy;
 use std::sync::RwLock;
+use std::time::Duration;
 use tauri::{
     image::Image,
     menu::{MenuBuilder, MenuItemBuilder},
     tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
-    AppHandle, Manager, WebviewWindow,
+    AppHandle, Emitter, Manager, WebviewWindow,
 };
 use tauri_plugin_autostart::{MacosLauncher, ManagerExt};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_notification::NotificationExt;
 use tauri_plugin_store::StoreExt;
-use types::{ContributionData, Settings};
+use timezone::UserTimezone;
+use types::{
+    Achievement, BadgeStyle, ContributionData, ContributionStats, ExportFormat, Goal, GoalProgress,
+    ImportBatch, ImportFormat, ImportSummary, LevelScale, MergePolicy, Projections, RangePreset,
+    RangeStats, RenderTheme, Settings, StreakHistory, StreakRecord, StreakRules,
+    StreakTimelinePoint, UserAchievements, UsernameValidation,
+};
 
 // ============================================================================
 // Global State
@@ -107,6 +138,29 @@ static CONTRIBUTION_CACHE: Lazy<RwLock<Option<ContributionData>>> =
 /// without re-specifying the username.
 static CURRENT_USERNAME: Lazy<RwLock<Option<String>>> = Lazy::new(|| RwLock::new(None));
 
+/// Last calendar year's total per user, as `(username, year, total)`.
+///
+/// Past years don't change, so the total is fetched once per user
+/// and year instead of on every projection.
+static LAST_YEAR_TOTAL: Lazy<RwLock<Option<(String, i32, u32)>>> =
+    Lazy::new(|| RwLock::new(None));
+
+/// The running local HTTP API, if enabled in the settings.
+static API_SERVER: Lazy<RwLock<Option<server::ApiServer>>> = Lazy::new(|| RwLock::new(None));
+
+/// Clock used for every "today" calculation.
+///
+/// Statistics never read the wall clock directly, so the whole app can
+/// be replayed at a fixed instant by setting `GIT_STREAKS_NOW` to an
+/// RFC 3339 timestamp (e.g. `2024-03-03T18:00:00Z`).
+static CLOCK: Lazy<Box<dyn Clock>> = Lazy::new(|| {
+    std::env::var("GIT_STREAKS_NOW")
+        .ok()
+        .and_then(|now| chrono::DateTime::parse_from_rfc3339(&now).ok())
+        .map(|now| Box::new(FixedClock(now.with_timezone(&chrono::Utc))) as Box<dyn Clock>)
+        .unwrap_or_else(|| Box::new(SystemClock))
+});
+
 // ============================================================================
 // Constants
 // ============================================================================
@@ -120,176 +174,1301 @@ const WINDOW_WIDTH: u32 = 420;
 /// Default window height in pixels.
 const WINDOW_HEIGHT: u32 = 520;
 
-// ============================================================================
-// Tauri Commands - Contributions
-// ============================================================================
+/// How often the midnight rollover task checks whether the day changed.
+///
+/// Checking periodically (rather than sleeping until midnight) keeps the
+/// rollover correct after timezone changes and system sleep.
+const ROLLOVER_CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+// ============================================================================
+// Tauri Commands - Contributions
+// ============================================================================
+
+/// Fetches GitHub contribution data for a specified user.
+///
+/// This command:
+/// 1. Makes an HTTP request to GitHub's contribution endpoint
+/// 2. Parses the HTML response to extract contribution data
+/// 3. Calculates statistics (streaks, totals, etc.) in the user's timezone,
+///    including imported days
+/// 4. Loads the avatar from the disk cache as a `data:` URI
+/// 5. Caches the result for quick subsequent access
+/// 6. Stores the username for refresh operations
+/// 7. Writes the streak badge, if a badge path is set
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+/// * `username` - The GitHub username to fetch contributions for
+///
+/// # Returns
+///
+/// * `Ok(ContributionData)` - The parsed contribution data
+/// * `Err(String)` - Error message if the fetch or parse fails
+///
+/// # Example (from frontend)
+///
+/// ```typescript
+/// const data = await invoke<ContributionData>("fetch_contributions", {
+///   username: "octocat"
+/// });
+/// ```
+#[tauri::command]
+async fn fetch_contributions(app: AppHandle, username: String) -> Result<ContributionData, String> {
+    let username = github::normalize_username(&username)?;
+    let today = CLOCK.today(&load_timezone(&app, &username));
+    let rules = load_streak_rules(&app);
+    let levels = load_level_scale(&app);
+    let mut data = github::fetch_contributions(&username, today, &rules, &levels).await?;
+
+    // Replay history imported from CSV or git log
+    if let Some(batches) = load_import_batches(&app).remove(&data.user.username) {
+        merge_into_data(&mut data, &batches, today, &rules, &levels);
+    }
+
+    // Serve the avatar from the local cache so it shows offline
+    if let Ok(cache_dir) = app.path().app_cache_dir() {
+        if let Ok(data_uri) =
+            github::cached_avatar_data_uri(&data.user.username, &data.user.avatar_url, &cache_dir)
+                .await
+        {
+            data.user.avatar_data_uri = Some(data_uri);
+        }
+    }
+
+    // Cache the data for quick retrieval
+    if let Ok(mut cache) = CONTRIBUTION_CACHE.write() {
+        *cache = Some(data.clone());
+    }
+
+    // Store the normalized username for refresh operations
+    if let Ok(mut current) = CURRENT_USERNAME.write() {
+        *current = Some(data.user.username.clone());
+    }
+
+    // Announce goals that were reached with the new data
+    announce_met_goals(&app, &data, today);
+
+    // Unlock milestones reached with the new data
+    unlock_achievements(&app, &data.user.username, &data.stats);
+
+    // Publish the README badge
+    write_badge(&app, &data.stats);
+
+    Ok(data)
+}
+
+/// Returns cached contribution data if available.
+///
+/// This is used for instant loading when the app opens, showing
+/// previously fetched data while fresh data is being loaded.
+///
+/// # Returns
+///
+/// * `Some(ContributionData)` - Cached data if available
+/// * `None` - No cached data exists
+#[tauri::command]
+fn get_cached_contributions() -> Option<ContributionData> {
+    CONTRIBUTION_CACHE.read().ok()?.clone()
+}
+
+/// Refreshes contribution data for the currently stored username.
+///
+/// This is useful for manual refresh operations where the username
+/// is already known from a previous fetch.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+///
+/// # Returns
+///
+/// * `Ok(ContributionData)` - Fresh contribution data
+/// * `Err(String)` - Error if no username is stored or fetch fails
+#[tauri::command]
+async fn refresh_contributions(app: AppHandle) -> Result<ContributionData, String> {
+    let username = CURRENT_USERNAME
+        .read()
+        .ok()
+        .and_then(|u| u.clone())
+        .ok_or_else(|| "No username set".to_string())?;
+
+    fetch_contributions(app, username).await
+}
+
+/// Calculates statistics as they were on a given date.
+///
+/// Uses the cached contribution days, ignoring everything after
+/// `date`. The current streak is the one that was active on that
+/// date, which answers questions like "what was my streak on March 3rd".
+///
+/// # Arguments
+///
+/// * `date` - Reference date (YYYY-MM-DD)
+///
+/// # Returns
+///
+/// * `Ok(ContributionStats)` - Statistics as of the given date
+/// * `Err(String)` - Error if the date is invalid or nothing is cached
+///
+/// # Example (from frontend)
+///
+/// ```typescript
+/// const stats = await invoke<ContributionStats>("get_stats_as_of", {
+///   date: "2024-03-03"
+/// });
+/// ```
+#[tauri::command]
+fn get_stats_as_of(app: AppHandle, date: String) -> Result<ContributionStats, String> {
+    let as_of = parse_date(&date)?;
+    let data = cached_data()?;
+    let mut stats = github::calculate_stats(&data.days(), as_of, &load_streak_rules(&app));
+    // The header total only describes the full fetched range
+    stats.reported_total = None;
+    Ok(stats)
+}
+
+/// Replays the current and longest streak for every day in a range.
+///
+/// Rest days configured in the settings are skipped.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+/// * `start` - First date (YYYY-MM-DD), defaults to the first cached day
+/// * `end` - Last date (YYYY-MM-DD), defaults to the last cached day
+///
+/// # Returns
+///
+/// * `Ok(Vec<StreakTimelinePoint>)` - One point per day in the range
+/// * `Err(String)` - Error if a date is invalid or nothing is cached
+#[tauri::command]
+fn get_streak_timeline(
+    app: AppHandle,
+    start: Option<String>,
+    end: Option<String>,
+) -> Result<Vec<StreakTimelinePoint>, String> {
+    let days = cached_data()?.days();
+    let (Some(first), Some(last)) = (days.first(), days.last()) else {
+        return Ok(Vec::new());
+    };
+
+    let start = parse_date(start.as_deref().unwrap_or(&first.date))?;
+    let end = parse_date(end.as_deref().unwrap_or(&last.date))?;
+
+    Ok(github::calculate_streak_timeline(
+        &days,
+        start,
+        end,
+        &load_streak_rules(&app),
+    ))
+}
+
+/// Calculates statistics for the date range the user is viewing.
+///
+/// Either a heatmap preset or an explicit range can be given. Without
+/// either, the full cached range is used.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+/// * `preset` - Heatmap view ("30d", "12w" or "year")
+/// * `start` - First date (YYYY-MM-DD), defaults to the first cached day
+/// * `end` - Last date (YYYY-MM-DD), defaults to today
+///
+/// # Returns
+///
+/// * `Ok(RangeStats)` - Statistics and weeks for the range
+/// * `Err(String)` - Error if a date is invalid, the range is empty or nothing is cached
+///
+/// # Example (from frontend)
+///
+/// ```typescript
+/// const range = await invoke<RangeStats>("get_range_stats", { preset: "30d" });
+/// ```
+#[tauri::command]
+fn get_range_stats(
+    app: AppHandle,
+    preset: Option<RangePreset>,
+    start: Option<String>,
+    end: Option<String>,
+) -> Result<RangeStats, String> {
+    let data = cached_data()?;
+    let days = data.days();
+    let Some(first) = days.first() else {
+        return Err("No contribution data loaded".to_string());
+    };
+
+    let today = CLOCK.today(&load_timezone(&app, &data.user.username));
+    let first_day = parse_date(&first.date)?;
+
+    let (start, end) = match preset {
+        Some(preset) => github::resolve_range_preset(preset, today, first_day),
+        None => resolve_dates(start.as_deref(), end.as_deref(), first_day, today)?,
+    };
+
+    Ok(github::calculate_range_stats(
+        &days,
+        start,
+        end,
+        today,
+        &load_streak_rules(&app),
+    ))
+}
+
+/// Returns every streak and the gaps between them.
+///
+/// Uses the configured streak rules and today's date in the tracked
+/// user's timezone.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+///
+/// # Returns
+///
+/// * `Ok(StreakHistory)` - All streaks, gaps and the days since the last contribution
+/// * `Err(String)` - Error if nothing is cached
+#[tauri::command]
+fn get_streak_history(app: AppHandle) -> Result<StreakHistory, String> {
+    let data = cached_data()?;
+    let today = CLOCK.today(&load_timezone(&app, &data.user.username));
+
+    Ok(github::calculate_streak_history(
+        &data.days(),
+        today,
+        &load_streak_rules(&app),
+    ))
+}
+
+/// Returns the longest streaks in the history, longest first.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+/// * `limit` - Maximum number of streaks (default: 5)
+///
+/// # Returns
+///
+/// * `Ok(Vec<StreakRecord>)` - Up to `limit` streaks
+/// * `Err(String)` - Error if nothing is cached
+///
+/// # Example (from frontend)
+///
+/// ```typescript
+/// const top = await invoke<StreakRecord[]>("get_top_streaks", { limit: 3 });
+/// ```
+#[tauri::command]
+fn get_top_streaks(app: AppHandle, limit: Option<usize>) -> Result<Vec<StreakRecord>, String> {
+    let history = get_streak_history(app)?;
+    Ok(github::top_streaks(&history, limit.unwrap_or(5)))
+}
+
+/// Projects the next streak milestone and the year-end total.
+///
+/// Last year's total is fetched from GitHub once per user and year;
+/// if that fails (e.g. offline) the "beat last year" fields are empty.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+///
+/// # Returns
+///
+/// * `Ok(Projections)` - Milestone date, year-end projection and last-year target
+/// * `Err(String)` - Error if nothing is cached
+#[tauri::command]
+async fn get_projections(app: AppHandle) -> Result<Projections, String> {
+    let data = cached_data()?;
+    let username = data.user.username.clone();
+    let today = CLOCK.today(&load_timezone(&app, &username));
+    let last_year = today.year() - 1;
+
+    let cached_total = LAST_YEAR_TOTAL.read().ok().and_then(|cached| {
+        cached
+            .as_ref()
+            .filter(|(user, year, _)| *user == username && *year == last_year)
+            .map(|(_, _, total)| *total)
+    });
+    let last_year_total = match cached_total {
+        Some(total) => Some(total),
+        None => {
+            let total = github::fetch_year_total(&username, last_year).await.ok();
+            if let (Some(total), Ok(mut cached)) = (total, LAST_YEAR_TOTAL.write()) {
+                *cached = Some((username, last_year, total));
+            }
+            total
+        }
+    };
+
+    Ok(projections::calculate_projections(
+        &data.days(),
+        &data.stats,
+        today,
+        &load_streak_rules(&app),
+        last_year_total,
+    ))
+}
+
+/// Returns a copy of the cached contribution data.
+///
+/// # Returns
+///
+/// * `Ok(ContributionData)` - The cached data
+/// * `Err(String)` - Error if nothing has been fetched yet
+fn cached_data() -> Result<ContributionData, String> {
+    CONTRIBUTION_CACHE
+        .read()
+        .ok()
+        .and_then(|cache| cache.clone())
+        .ok_or_else(|| "No contribution data loaded".to_string())
+}
+
+/// Resolves an optional date range passed from the frontend.
+///
+/// # Arguments
+///
+/// * `start` - First date (YYYY-MM-DD), defaults to `first_day`
+/// * `end` - Last date (YYYY-MM-DD), defaults to `today`
+/// * `first_day` - First cached day
+/// * `today` - Today's date in the tracked user's timezone
+///
+/// # Returns
+///
+/// * `Ok((NaiveDate, NaiveDate))` - The start and end date
+/// * `Err(String)` - Error if a date is invalid or the range is empty
+fn resolve_dates(
+    start: Option<&str>,
+    end: Option<&str>,
+    first_day: NaiveDate,
+    today: NaiveDate,
+) -> Result<(NaiveDate, NaiveDate), String> {
+    let start = start.map(parse_date).transpose()?.unwrap_or(first_day);
+    let end = end.map(parse_date).transpose()?.unwrap_or(today);
+
+    if start > end {
+        return Err(format!("Range start {} is after its end {}", start, end));
+    }
+
+    Ok((start, end))
+}
+
+/// Parses a YYYY-MM-DD date passed from the frontend.
+fn parse_date(date: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| format!("Invalid date \"{}\"", date))
+}
+
+// ============================================================================
+// Tauri Commands - Settings
+// ============================================================================
+
+/// Retrieves the current application settings.
+///
+/// Settings are loaded from the persistent store and merged with
+/// the actual autostart state from the system.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+///
+/// # Returns
+///
+/// The current settings, or defaults if the store is unavailable
+#[tauri::command]
+fn get_settings(app: AppHandle) -> Settings {
+    let store = match app.store(STORE_PATH) {
+        Ok(s) => s,
+        Err(_) => return Settings::default(),
+    };
+
+    // Check actual autostart state from the system
+    let launch_at_login = app.autolaunch().is_enabled().unwrap_or(false);
+
+    Settings {
+        username: store
+            .get("username")
+            .and_then(|v| v.as_str().map(String::from))
+            .unwrap_or_default(),
+        update_interval: store
+            .get("updateInterval")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(3600),
+        icon_style: store
+            .get("iconStyle")
+            .and_then(|v| v.as_str().map(String::from))
+            .unwrap_or_else(|| "green".to_string()),
+        launch_at_login,
+        theme: store
+            .get("theme")
+            .and_then(|v| v.as_str().map(String::from))
+            .unwrap_or_else(|| "system".to_string()),
+        timezones: store
+            .get("timezones")
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default(),
+        rest_days: store
+            .get("restDays")
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default(),
+        streak_threshold: store
+            .get("streakThreshold")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32)
+            .unwrap_or(1),
+        weekly_target: store
+            .get("weeklyTarget")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32)
+            .unwrap_or(1),
+        level_thresholds: store
+            .get("levelThresholds")
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default(),
+        badge_path: store
+            .get("badgePath")
+            .and_then(|v| v.as_str().map(String::from))
+            .unwrap_or_default(),
+        badge_style: store
+            .get("badgeStyle")
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default(),
+        badge_theme: store
+            .get("badgeTheme")
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default(),
+        api_enabled: store
+            .get("apiEnabled")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        api_port: store
+            .get("apiPort")
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_else(types::default_api_port),
+        api_token: store
+            .get("apiToken")
+            .and_then(|v| v.as_str().map(String::from))
+            .unwrap_or_default(),
+    }
+}
+
+/// Saves application settings to persistent storage.
+///
+/// This also updates the in-memory username, recalculates cached
+/// statistics for the user's timezone and configures the system
+/// autostart setting.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+/// * `settings` - The settings to save
+///
+/// # Returns
+///
+/// * `Ok(())` - Settings saved successfully
+/// * `Err(String)` - Error message if the username, a timezone, the rest days, a
+///   streak threshold, the level thresholds, the badge path or the API port are
+///   invalid, or save fails
+#[tauri::command]
+fn save_settings(app: AppHandle, mut settings: Settings) -> Result<(), String> {
+    // Store the normalized login; an empty username resets to the welcome screen
+    if !settings.username.trim().is_empty() {
+        settings.username = github::normalize_username(&settings.username)?;
+    } else {
+        settings.username = String::new();
+    }
+
+    // Key timezones by normalized login, and reject unknown timezones
+    // before anything is persisted
+    settings.timezones = settings
+        .timezones
+        .into_iter()
+        .map(|(username, timezone)| {
+            UserTimezone::parse(&timezone)?;
+            Ok((github::normalize_username(&username)?, timezone))
+        })
+        .collect::<Result<_, String>>()?;
+
+    // Rest days are weekdays 0-6, and at least one day has to count
+    settings.rest_days.sort_unstable();
+    settings.rest_days.dedup();
+    if settings.rest_days.iter().any(|day| *day > 6) {
+        return Err("Rest days must be weekdays from 0 (Sunday) to 6 (Saturday)".to_string());
+    }
+    if settings.rest_days.len() == 7 {
+        return Err("At least one day of the week must count towards streaks".to_string());
+    }
+    if settings.streak_threshold == 0 {
+        return Err("Streak threshold must be at least 1 contribution".to_string());
+    }
+    if settings.weekly_target == 0 {
+        return Err("Weekly target must be at least 1 contribution".to_string());
+    }
+
+    // Level thresholds are either unset (quartiles) or four ascending minimums
+    let thresholds = &settings.level_thresholds;
+    if !thresholds.is_empty()
+        && (thresholds.len() != 4
+            || thresholds[0] == 0
+            || thresholds.windows(2).any(|pair| pair[0] >= pair[1]))
+    {
+        return Err(
+            "Level thresholds must be four ascending counts starting at 1 or more".to_string(),
+        );
+    }
+
+    // The badge is written from the background, so a relative path would
+    // depend on the working directory
+    settings.badge_path = settings.badge_path.trim().to_string();
+    if !settings.badge_path.is_empty() && !std::path::Path::new(&settings.badge_path).is_absolute()
+    {
+        return Err("Badge path must be an absolute file path".to_string());
+    }
+
+    // Ports below 1024 need elevated privileges on most systems
+    if settings.api_port < 1024 {
+        return Err("API port must be between 1024 and 65535".to_string());
+    }
+    settings.api_token = settings.api_token.trim().to_string();
+
+    let store = app.store(STORE_PATH).map_err(|e| e.to_string())?;
+
+    // The API never runs without a token: keep the saved one, or create one
+    // the first time the API is enabled
+    if settings.api_enabled && settings.api_token.is_empty() {
+        let saved = store
+            .get("apiToken")
+            .and_then(|v| v.as_str().map(String::from))
+            .unwrap_or_default();
+        settings.api_token = if saved.is_empty() {
+            server::generate_token()?
+        } else {
+            saved
+        };
+    }
+
+    // Persist settings to store
+    store.set("username", serde_json::json!(settings.username));
+    store.set(
+        "updateInterval",
+        serde_json::json!(settings.update_interval),
+    );
+    store.set("iconStyle", serde_json::json!(settings.icon_style));
+    store.set("theme", serde_json::json!(settings.theme));
+    store.set("timezones", serde_json::json!(settings.timezones));
+    store.set("restDays", serde_json::json!(settings.rest_days));
+    store.set(
+        "streakThreshold",
+        serde_json::json!(settings.streak_threshold),
+    );
+    store.set("weeklyTarget", serde_json::json!(settings.weekly_target));
+    store.set(
+        "levelThresholds",
+        serde_json::json!(settings.level_thresholds),
+    );
+    store.set("badgePath", serde_json::json!(settings.badge_path));
+    store.set("badgeStyle", serde_json::json!(settings.badge_style));
+    store.set("badgeTheme", serde_json::json!(settings.badge_theme));
+    store.set("apiEnabled", serde_json::json!(settings.api_enabled));
+    store.set("apiPort", serde_json::json!(settings.api_port));
+    store.set("apiToken", serde_json::json!(settings.api_token));
+
+    store.save().map_err(|e| e.to_string())?;
+
+    // Start, restart or stop the local HTTP API
+    configure_api_server(&app);
+
+    // Streaks depend on the user's timezone and streak rules, and levels on the
+    // level scale, so refresh the cached data
+    let rules = settings.streak_rules();
+    let levels = settings.level_scale();
+    let stats = CONTRIBUTION_CACHE.write().ok().and_then(|mut cache| {
+        let data = cache.as_mut()?;
+        // The cache holds the previously fetched user until the next fetch,
+        // which may not be the username just saved
+        let timezone = settings.timezone_for(&data.user.username);
+        let today = CLOCK.today(&UserTimezone::parse(timezone).unwrap_or_default());
+        github::assign_week_levels(&mut data.weeks, &levels);
+        github::recalculate_stats(data, today, &rules);
+        Some(data.stats.clone())
+    });
+
+    // Write the badge after the cache lock is released, so readers don't wait on disk I/O
+    if let Some(stats) = stats {
+        write_badge(&app, &stats);
+    }
+
+    // Update current username in memory for refresh operations
+    if let Ok(mut current) = CURRENT_USERNAME.write() {
+        *current = Some(settings.username);
+    }
+
+    // Configure system autostart
+    let autostart = app.autolaunch();
+    if settings.launch_at_login {
+        let _ = autostart.enable();
+    } else {
+        let _ = autostart.disable();
+    }
+
+    Ok(())
+}
+
+/// Validates a GitHub username before it is saved.
+///
+/// Normalizes the input (trims whitespace, strips a leading `@`,
+/// lowercases) and checks it against GitHub's login rules. When
+/// `check_exists` is set, a lightweight request confirms the
+/// account exists.
+///
+/// # Arguments
+///
+/// * `username` - Username as typed in the settings form
+/// * `check_exists` - Whether to ask GitHub if the account exists (default: false)
+///
+/// # Returns
+///
+/// The validation result with the normalized username
+///
+/// # Example (from frontend)
+///
+/// ```typescript
+/// const result = await invoke<UsernameValidation>("validate_username", {
+///   username: " @Octocat ",
+///   checkExists: true
+/// });
+/// // { username: "octocat", valid: true, exists: true, error: null }
+/// ```
+#[tauri::command]
+async fn validate_username(username: String, check_exists: Option<bool>) -> UsernameValidation {
+    github::validate_username(&username, check_exists.unwrap_or(false)).await
+}
+
+// ============================================================================
+// Tauri Commands - Goals
+// ============================================================================
+
+/// Returns the saved contribution goals.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+///
+/// # Returns
+///
+/// The goals, or an empty list if none are saved
+#[tauri::command]
+fn get_goals(app: AppHandle) -> Vec<Goal> {
+    load_goals(&app)
+}
+
+/// Saves the contribution goals, replacing the existing ones.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+/// * `goals` - The complete list of goals
+///
+/// # Returns
+///
+/// * `Ok(())` - Goals saved successfully
+/// * `Err(String)` - Error if a goal is invalid or the save fails
+///
+/// # Example (from frontend)
+///
+/// ```typescript
+/// await invoke("save_goals", {
+///   goals: [{ id: "daily", name: "Daily five", period: "day", target: 5, weekdays: [] }]
+/// });
+/// ```
+#[tauri::command]
+fn save_goals(app: AppHandle, goals: Vec<Goal>) -> Result<(), String> {
+    goals::validate_goals(&goals)?;
+
+    let store = app.store(STORE_PATH).map_err(|e| e.to_string())?;
+    store.set("goals", serde_json::json!(goals));
+    store.save().map_err(|e| e.to_string())
+}
+
+/// Calculates the progress of every goal from the cached data.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+///
+/// # Returns
+///
+/// * `Ok(Vec<GoalProgress>)` - Progress per goal, in the saved order
+/// * `Err(String)` - Error if nothing is cached
+#[tauri::command]
+fn get_goal_progress(app: AppHandle) -> Result<Vec<GoalProgress>, String> {
+    let data = cached_data()?;
+    let today = CLOCK.today(&load_timezone(&app, &data.user.username));
+    let days = data.days();
+
+    Ok(load_goals(&app)
+        .iter()
+        .map(|goal| goals::calculate_goal_progress(goal, &days, today))
+        .collect())
+}
+
+/// Loads the goals from the settings store.
+fn load_goals(app: &AppHandle) -> Vec<Goal> {
+    app.store(STORE_PATH)
+        .ok()
+        .and_then(|store| store.get("goals"))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+/// Emits a "goal-met" event for goals reached in the current period.
+///
+/// Each goal is announced at most once per period and user. The last
+/// announced period per user and goal is kept in the store
+/// (`goalsAnnounced`) so restarts and refreshes don't repeat the event,
+/// while switching users still announces goals the other user meets.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+/// * `data` - Freshly fetched contribution data
+/// * `today` - Today's date in the user's timezone
+fn announce_met_goals(app: &AppHandle, data: &ContributionData, today: NaiveDate) {
+    let goals = load_goals(app);
+    if goals.is_empty() {
+        return;
+    }
+    let Ok(store) = app.store(STORE_PATH) else {
+        return;
+    };
+
+    let mut announced: std::collections::HashMap<
+        String,
+        std::collections::HashMap<String, String>,
+    > = store
+        .get("goalsAnnounced")
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default();
+    let user = announced.entry(data.user.username.clone()).or_default();
+
+    let days = data.days();
+    let mut changed = false;
+    for goal in &goals {
+        let progress = goals::calculate_goal_progress(goal, &days, today);
+        if !progress.met || user.get(&goal.id) == Some(&progress.period_start) {
+            continue;
+        }
+
+        user.insert(goal.id.clone(), progress.period_start.clone());
+        changed = true;
+        let _ = app.emit("goal-met", progress);
+    }
+
+    if changed {
+        store.set("goalsAnnounced", serde_json::json!(announced));
+        let _ = store.save();
+    }
+}
+
+// ============================================================================
+// Tauri Commands - Export
+// ============================================================================
+
+/// Exports the cached days, statistics and streaks to a file.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+/// * `path` - Destination file chosen by the user
+/// * `format` - "csv" or "json"
+/// * `start` - First date (YYYY-MM-DD), defaults to the first cached day
+/// * `end` - Last date (YYYY-MM-DD), defaults to today
+///
+/// # Returns
+///
+/// * `Ok(())` - The file was written
+/// * `Err(String)` - Error if nothing is cached, a date is invalid or the write fails
+///
+/// # Example (from frontend)
+///
+/// ```typescript
+/// await invoke("export_contributions", {
+///   path: "/Users/me/contributions.csv",
+///   format: "csv",
+///   start: "2024-01-01"
+/// });
+/// ```
+#[tauri::command]
+fn export_contributions(
+    app: AppHandle,
+    path: String,
+    format: ExportFormat,
+    start: Option<String>,
+    end: Option<String>,
+) -> Result<(), String> {
+    if path.trim().is_empty() {
+        return Err("Export path must not be empty".to_string());
+    }
+
+    let data = cached_data()?;
+    let days = data.days();
+    let Some(first) = days.first() else {
+        return Err("No contribution data loaded".to_string());
+    };
+
+    let today = CLOCK.today(&load_timezone(&app, &data.user.username));
+    let (start, end) = resolve_dates(
+        start.as_deref(),
+        end.as_deref(),
+        parse_date(&first.date)?,
+        today,
+    )?;
+
+    let rules = load_streak_rules(&app);
+    let range = github::calculate_range_stats(&days, start, end, today, &rules);
+    let export = export::build_export(
+        &data.user.username,
+        range,
+        today,
+        &rules,
+        &CLOCK.now().to_rfc3339(),
+    );
+
+    let contents = export::render_export(&export, format)?;
+    std::fs::write(&path, contents).map_err(|e| format!("Failed to write {}: {}", path, e))
+}
+
+/// Renders the cached heatmap as an SVG image.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+/// * `path` - Optional file to write the SVG to
+/// * `theme` - "light" or "dark"; defaults to the theme setting
+/// * `include_stats` - Whether to add the stats footer (default: true)
+///
+/// # Returns
+///
+/// * `Ok(String)` - The SVG document
+/// * `Err(String)` - Error if nothing is cached or the write fails
+///
+/// # Example (from frontend)
+///
+/// ```typescript
+/// const svg = await invoke<string>("export_heatmap_svg", {
+///   path: "/Users/me/heatmap.svg",
+///   theme: "dark"
+/// });
+/// ```
+#[tauri::command]
+fn export_heatmap_svg(
+    app: AppHandle,
+    path: Option<String>,
+    theme: Option<RenderTheme>,
+    include_stats: Option<bool>,
+) -> Result<String, String> {
+    let data = cached_data()?;
+    let theme = theme.unwrap_or_else(|| load_render_theme(&app));
+    let stats = include_stats.unwrap_or(true).then_some(&data.stats);
+
+    let svg = render::render_heatmap_svg(&data.weeks, theme, stats);
+    if let Some(path) = path {
+        std::fs::write(&path, &svg).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    }
+    Ok(svg)
+}
 
-/// Fetches GitHub contribution data for a specified user.
+/// Renders the cached data as a PNG stats card.
 ///
-/// This command:
-/// 1. Makes an HTTP request to GitHub's contribution endpoint
-/// 2. Parses the HTML response to extract contribution data
-/// 3. Calculates statistics (streaks, totals, etc.)
-/// 4. Caches the result for quick subsequent access
-/// 5. Stores the username for refresh operations
+/// The card is rasterized on the CPU, so this also works while the
+/// window is hidden. It can be written to a file, copied to the
+/// clipboard, or both.
 ///
 /// # Arguments
 ///
-/// * `username` - The GitHub username to fetch contributions for
+/// * `app` - The Tauri application handle
+/// * `path` - Optional file to write the PNG to
+/// * `theme` - "light" or "dark"; defaults to the theme setting
+/// * `copy` - Whether to copy the image to the clipboard (default: false)
 ///
 /// # Returns
 ///
-/// * `Ok(ContributionData)` - The parsed contribution data
-/// * `Err(String)` - Error message if the fetch or parse fails
+/// * `Ok(String)` - The PNG as a `data:image/png;base64,` URI (for previews)
+/// * `Err(String)` - Error if nothing is cached, rendering fails, or the
+///   write or copy fails
 ///
 /// # Example (from frontend)
 ///
 /// ```typescript
-/// const data = await invoke<ContributionData>("fetch_contributions", {
-///   username: "octocat"
+/// const preview = await invoke<string>("export_stats_card", {
+///   path: "/Users/me/card.png",
+///   copy: true
 /// });
 /// ```
 #[tauri::command]
-async fn fetch_contributions(username: String) -> Result<ContributionData, String> {
-    let data = github::fetch_contributions(&username).await?;
+fn export_stats_card(
+    app: AppHandle,
+    path: Option<String>,
+    theme: Option<RenderTheme>,
+    copy: Option<bool>,
+) -> Result<String, String> {
+    let data = cached_data()?;
+    let theme = theme.unwrap_or_else(|| load_render_theme(&app));
 
-    // Cache the data for quick retrieval
-    if let Ok(mut cache) = CONTRIBUTION_CACHE.write() {
-        *cache = Some(data.clone());
+    let png = render::render_stats_card(&data, theme)?;
+    if let Some(path) = path {
+        std::fs::write(&path, &png).map_err(|e| format!("Failed to write {}: {}", path, e))?;
     }
-
-    // Store current username for refresh operations
-    if let Ok(mut current) = CURRENT_USERNAME.write() {
-        *current = Some(username);
+    if copy.unwrap_or(false) {
+        let rgba = image::load_from_memory(&png)
+            .map_err(|e| format!("Failed to decode card: {}", e))?
+            .to_rgba8();
+        let (width, height) = rgba.dimensions();
+        app.clipboard()
+            .write_image(&Image::new_owned(rgba.into_raw(), width, height))
+            .map_err(|e| format!("Failed to copy card: {}", e))?;
     }
 
-    Ok(data)
+    Ok(format!(
+        "data:image/png;base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(&png)
+    ))
 }
 
-/// Returns cached contribution data if available.
+/// Renders the streak badge for the cached statistics.
 ///
-/// This is used for instant loading when the app opens, showing
-/// previously fetched data while fresh data is being loaded.
+/// The same badge is written to `badgePath` after every refresh; this
+/// command is for previews and one-off exports.
+///
+/// # Arguments
+///
+/// * `path` - Optional file to write the SVG to
+/// * `style` - "flat", "flat-square" or "card" (default: flat)
+/// * `theme` - "light" or "dark" (default: light)
 ///
 /// # Returns
 ///
-/// * `Some(ContributionData)` - Cached data if available
-/// * `None` - No cached data exists
+/// * `Ok(String)` - The SVG document
+/// * `Err(String)` - Error if nothing is cached or the write fails
+///
+/// # Example (from frontend)
+///
+/// ```typescript
+/// const svg = await invoke<string>("export_streak_badge", { style: "card" });
+/// ```
 #[tauri::command]
-fn get_cached_contributions() -> Option<ContributionData> {
-    CONTRIBUTION_CACHE.read().ok()?.clone()
+fn export_streak_badge(
+    path: Option<String>,
+    style: Option<BadgeStyle>,
+    theme: Option<RenderTheme>,
+) -> Result<String, String> {
+    let data = cached_data()?;
+    let svg = render::render_streak_badge(
+        &data.stats,
+        style.unwrap_or_default(),
+        theme.unwrap_or_default(),
+    );
+    if let Some(path) = path {
+        std::fs::write(&path, &svg).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    }
+    Ok(svg)
 }
 
-/// Refreshes contribution data for the currently stored username.
+/// Writes the streak badge to the configured `badgePath`.
 ///
-/// This is useful for manual refresh operations where the username
-/// is already known from a previous fetch.
+/// Does nothing when no path is set. A failed write doesn't fail the
+/// refresh; it is reported to the frontend as a "badge-error" event.
 ///
-/// # Returns
+/// The badge is usually published by a sync folder or git hook, so it
+/// is written to a temporary file next to it and renamed into place;
+/// readers never see a half-written badge.
 ///
-/// * `Ok(ContributionData)` - Fresh contribution data
-/// * `Err(String)` - Error if no username is stored or fetch fails
-#[tauri::command]
-async fn refresh_contributions() -> Result<ContributionData, String> {
-    let username = CURRENT_USERNAME
-        .read()
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+/// * `stats` - Freshly calculated statistics
+fn write_badge(app: &AppHandle, stats: &ContributionStats) {
+    let Ok(store) = app.store(STORE_PATH) else {
+        return;
+    };
+    let path = store
+        .get("badgePath")
+        .and_then(|v| v.as_str().map(String::from))
+        .unwrap_or_default();
+    if path.is_empty() {
+        return;
+    }
+
+    let style = store
+        .get("badgeStyle")
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default();
+    let theme = store
+        .get("badgeTheme")
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default();
+
+    let svg = render::render_streak_badge(stats, style, theme);
+    if let Err(e) = write_replacing(std::path::Path::new(&path), svg.as_bytes()) {
+        let _ = app.emit("badge-error", format!("Failed to write {}: {}", path, e));
+    }
+}
+
+/// Writes a file by renaming a temporary sibling over it.
+///
+/// The rename is atomic on the same file system, so the file always
+/// holds either the old or the new contents.
+fn write_replacing(path: &std::path::Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    let temp = std::path::PathBuf::from(temp);
+
+    std::fs::write(&temp, contents)?;
+    std::fs::rename(&temp, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&temp);
+    })
+}
+
+/// Resolves the theme setting to a concrete theme for rendered images.
+///
+/// "system" follows the main window's theme, falling back to light
+/// when there is no window (e.g. rendering in the background).
+fn load_render_theme(app: &AppHandle) -> RenderTheme {
+    let setting = app
+        .store(STORE_PATH)
         .ok()
-        .and_then(|u| u.clone())
-        .ok_or_else(|| "No username set".to_string())?;
+        .and_then(|store| store.get("theme"))
+        .and_then(|v| v.as_str().map(String::from))
+        .unwrap_or_else(|| "system".to_string());
+    let system_dark = app
+        .get_webview_window("main")
+        .and_then(|window| window.theme().ok())
+        .is_some_and(|theme| theme == tauri::Theme::Dark);
 
-    fetch_contributions(username).await
+    RenderTheme::from_setting(&setting, system_dark)
 }
 
 // ============================================================================
-// Tauri Commands - Settings
+// Tauri Commands - Import
 // ============================================================================
 
-/// Retrieves the current application settings.
+/// Imports day counts from a CSV file or saved `git log` output.
 ///
-/// Settings are loaded from the persistent store and merged with
-/// the actual autostart state from the system.
+/// The days are merged into the cached data right away and stored per
+/// user as a batch with its policy, so they are replayed on every later
+/// fetch as well. Stats only cover GitHub's calendar window, so days
+/// before its first day or after today are skipped and counted in the
+/// summary instead of being stored.
 ///
 /// # Arguments
 ///
 /// * `app` - The Tauri application handle
+/// * `path` - File to read
+/// * `format` - "csv" or "git-log" (`git log --format=%ad --date=short`)
+/// * `policy` - "max", "sum" or "replace" (default: max)
 ///
 /// # Returns
 ///
-/// The current settings, or defaults if the store is unavailable
+/// * `Ok(ImportSummary)` - Number of days and contributions imported, and
+///   the number of days skipped
+/// * `Err(String)` - Error if no username is set, nothing is cached for
+///   the user yet, the file can't be read or parsed, no day falls inside
+///   the calendar window, or saving fails
+///
+/// # Example (from frontend)
+///
+/// ```typescript
+/// const summary = await invoke<ImportSummary>("import_contributions", {
+///   path: "/Users/me/commits.txt",
+///   format: "git-log",
+///   policy: "sum"
+/// });
+/// ```
 #[tauri::command]
-fn get_settings(app: AppHandle) -> Settings {
-    let store = match app.store(STORE_PATH) {
-        Ok(s) => s,
-        Err(_) => return Settings::default(),
+fn import_contributions(
+    app: AppHandle,
+    path: String,
+    format: ImportFormat,
+    policy: Option<MergePolicy>,
+) -> Result<ImportSummary, String> {
+    let username = CURRENT_USERNAME
+        .read()
+        .ok()
+        .and_then(|u| u.clone())
+        .ok_or_else(|| "No username set".to_string())?;
+    let policy = policy.unwrap_or_default();
+    let today = CLOCK.today(&load_timezone(&app, &username));
+
+    // The window starts at GitHub's first calendar day for this user
+    let window_start = CONTRIBUTION_CACHE
+        .read()
+        .ok()
+        .and_then(|cache| {
+            let data = cache
+                .as_ref()
+                .filter(|data| data.user.username == username)?;
+            NaiveDate::parse_from_str(&data.weeks.first()?.days.first()?.date, "%Y-%m-%d").ok()
+        })
+        .ok_or_else(|| "Fetch contributions before importing".to_string())?;
+
+    let text =
+        std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let mut days = import::parse_import(&text, format)?;
+
+    // Future days can't have contributions yet, and older days wouldn't show
+    let skipped_days = import::retain_window(&mut days, window_start, today);
+    let (Some(first), Some(last)) = (days.first(), days.last()) else {
+        return Err(format!(
+            "All {} days in {} are outside GitHub's calendar window ({} to {})",
+            skipped_days, path, window_start, today
+        ));
+    };
+    let summary = ImportSummary {
+        days: days.len() as u32,
+        contributions: days
+            .iter()
+            .fold(0u32, |total, day| total.saturating_add(day.count)),
+        skipped_days,
+        start_date: first.date.clone(),
+        end_date: last.date.clone(),
     };
 
-    // Check actual autostart state from the system
-    let launch_at_login = app.autolaunch().is_enabled().unwrap_or(false);
+    // Store the import as its own batch so later fetches replay it
+    let store = app.store(STORE_PATH).map_err(|e| e.to_string())?;
+    let batch = ImportBatch { policy, days };
+    let mut imports = load_import_batches(&app);
+    imports
+        .entry(username.clone())
+        .or_default()
+        .push(batch.clone());
+    store.set("importedDays", serde_json::json!(imports));
+    store.save().map_err(|e| e.to_string())?;
 
-    Settings {
-        username: store
-            .get("username")
-            .and_then(|v| v.as_str().map(String::from))
-            .unwrap_or_default(),
-        update_interval: store
-            .get("updateInterval")
-            .and_then(|v| v.as_u64())
-            .unwrap_or(3600),
-        icon_style: store
-            .get("iconStyle")
-            .and_then(|v| v.as_str().map(String::from))
-            .unwrap_or_else(|| "green".to_string()),
-        launch_at_login,
-        theme: store
-            .get("theme")
-            .and_then(|v| v.as_str().map(String::from))
-            .unwrap_or_else(|| "system".to_string()),
+    // The cache of the same user already has the earlier batches applied,
+    // so applying only the new one matches the replay on the next fetch
+    let rules = load_streak_rules(&app);
+    let levels = load_level_scale(&app);
+    if let Ok(mut cache) = CONTRIBUTION_CACHE.write() {
+        if let Some(data) = cache.as_mut().filter(|data| data.user.username == username) {
+            merge_into_data(data, std::slice::from_ref(&batch), today, &rules, &levels);
+        }
     }
+
+    Ok(summary)
 }
 
-/// Saves application settings to persistent storage.
+/// Loads the import batches of every user from the store, in import order.
+fn load_import_batches(app: &AppHandle) -> std::collections::HashMap<String, Vec<ImportBatch>> {
+    app.store(STORE_PATH)
+        .ok()
+        .and_then(|store| store.get("importedDays"))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
+
+/// Merges import batches into contribution data and recalculates levels
+/// and stats.
+///
+/// The weeks keep GitHub's calendar window. GitHub's header total
+/// doesn't include imported days, so it is cleared instead of flagging
+/// the merged total as an estimate.
+///
+/// # Arguments
 ///
-/// This also updates the in-memory username and configures
-/// the system autostart setting.
+/// * `data` - Contribution data to update
+/// * `batches` - Import batches, in import order
+/// * `today` - Today's date in the user's timezone
+/// * `rules` - Streak rules for the statistics
+/// * `levels` - Level scale for the merged series
+fn merge_into_data(
+    data: &mut ContributionData,
+    batches: &[ImportBatch],
+    today: NaiveDate,
+    rules: &StreakRules,
+    levels: &LevelScale,
+) {
+    let merged = import::apply_batches(&data.days(), batches, today);
+    data.weeks = github::group_into_weeks(merged);
+    github::assign_week_levels(&mut data.weeks, levels);
+    data.stats.reported_total = None;
+    github::recalculate_stats(data, today, rules);
+}
+
+// ============================================================================
+// Tauri Commands - Achievements
+// ============================================================================
+
+/// Returns every achievement the current user unlocked, with its unlock date.
 ///
 /// # Arguments
 ///
 /// * `app` - The Tauri application handle
-/// * `settings` - The settings to save
 ///
 /// # Returns
 ///
-/// * `Ok(())` - Settings saved successfully
-/// * `Err(String)` - Error message if save fails
+/// Unlocked achievements in the order they were unlocked, or an empty
+/// list if no username is set
 #[tauri::command]
-fn save_settings(app: AppHandle, settings: Settings) -> Result<(), String> {
-    let store = app.store(STORE_PATH).map_err(|e| e.to_string())?;
+fn get_achievements(app: AppHandle) -> Vec<Achievement> {
+    let Some(username) = CURRENT_USERNAME.read().ok().and_then(|u| u.clone()) else {
+        return Vec::new();
+    };
 
-    // Persist settings to store
-    store.set("username", serde_json::json!(settings.username));
-    store.set(
-        "updateInterval",
-        serde_json::json!(settings.update_interval),
-    );
-    store.set("iconStyle", serde_json::json!(settings.icon_style));
-    store.set("theme", serde_json::json!(settings.theme));
+    load_achievements(&app)
+        .remove(&username)
+        .map(|achievements| achievements.unlocked)
+        .unwrap_or_default()
+}
 
-    store.save().map_err(|e| e.to_string())?;
+/// Loads the achievements of every user from the settings store.
+fn load_achievements(app: &AppHandle) -> std::collections::HashMap<String, UserAchievements> {
+    app.store(STORE_PATH)
+        .ok()
+        .and_then(|store| store.get("achievements"))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default()
+}
 
-    // Update current username in memory for refresh operations
-    if let Ok(mut current) = CURRENT_USERNAME.write() {
-        *current = Some(settings.username);
+/// Detects newly unlocked achievements and announces them.
+///
+/// New achievements are persisted before they are announced, so each
+/// one triggers exactly one notification and one "achievement-unlocked"
+/// event, even if the app quits right after. The first fetch of a user
+/// seeds their best-day record without announcing it.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+/// * `username` - The user the statistics belong to
+/// * `stats` - Freshly calculated statistics
+fn unlock_achievements(app: &AppHandle, username: &str, stats: &ContributionStats) {
+    let Ok(store) = app.store(STORE_PATH) else {
+        return;
+    };
+
+    let mut all = load_achievements(app);
+    let user = all.entry(username.to_string()).or_default();
+    let new = achievements::detect_achievements(stats, user, &CLOCK.now().to_rfc3339());
+    let record_changed = achievements::record_best_day(user, stats);
+    if new.is_empty() && !record_changed {
+        return;
     }
 
-    // Configure system autostart
-    let autostart = app.autolaunch();
-    if settings.launch_at_login {
-        let _ = autostart.enable();
-    } else {
-        let _ = autostart.disable();
+    user.unlocked.extend(new.iter().cloned());
+    store.set("achievements", serde_json::json!(all));
+    if store.save().is_err() {
+        // Not persisted - announcing now would repeat on the next fetch
+        return;
     }
 
-    Ok(())
+    for achievement in new {
+        let _ = app
+            .notification()
+            .builder()
+            .title(&achievement.title)
+            .body(&achievement.description)
+            .show();
+        let _ = app.emit("achievement-unlocked", achievement);
+    }
 }
 
 // ============================================================================
@@ -615,6 +1794,224 @@ fn load_saved_username(app: &AppHandle) {
     }
 }
 
+/// Loads the timezone setting for a tracked user.
+///
+/// Falls back to the system timezone when the store is unavailable
+/// or no valid timezone is configured for the user.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+/// * `username` - A normalized GitHub login
+///
+/// # Returns
+///
+/// The timezone used to decide which day is "today" for the user
+fn load_timezone(app: &AppHandle, username: &str) -> UserTimezone {
+    app.store(STORE_PATH)
+        .ok()
+        .and_then(|store| store.get("timezones"))
+        .and_then(|v| v.get(username).and_then(|tz| tz.as_str().map(String::from)))
+        .and_then(|tz| UserTimezone::parse(&tz).ok())
+        .unwrap_or_default()
+}
+
+/// Loads the streak rules (rest days and thresholds) from the settings store.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+///
+/// # Returns
+///
+/// The configured rules, or the strict defaults if none are stored
+fn load_streak_rules(app: &AppHandle) -> StreakRules {
+    let Ok(store) = app.store(STORE_PATH) else {
+        return StreakRules::default();
+    };
+
+    StreakRules {
+        rest_days: store
+            .get("restDays")
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default(),
+        min_daily: store
+            .get("streakThreshold")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32)
+            .unwrap_or(1),
+        weekly_target: store
+            .get("weeklyTarget")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32)
+            .unwrap_or(1),
+    }
+}
+
+/// Loads the heatmap level scale from the settings store.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+///
+/// # Returns
+///
+/// Fixed thresholds if four are stored, GitHub-style quartiles otherwise
+fn load_level_scale(app: &AppHandle) -> LevelScale {
+    let thresholds: Vec<u32> = app
+        .store(STORE_PATH)
+        .ok()
+        .and_then(|store| store.get("levelThresholds"))
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default();
+
+    LevelScale::from_thresholds(&thresholds)
+}
+
+// ============================================================================
+// Local HTTP API
+// ============================================================================
+
+/// Starts, restarts or stops the local HTTP API to match the settings.
+///
+/// A running server is left alone when its port and token are
+/// unchanged. Settings saved before tokens were required may enable the
+/// API without one; a token is generated and saved for them. A failure
+/// to listen (e.g. the port is taken) doesn't fail the caller; it is
+/// reported to the frontend as an "api-error" event.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+fn configure_api_server(app: &AppHandle) {
+    let Ok(store) = app.store(STORE_PATH) else {
+        return;
+    };
+    let enabled = store
+        .get("apiEnabled")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let port = store
+        .get("apiPort")
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_else(types::default_api_port);
+    let mut token = store
+        .get("apiToken")
+        .and_then(|v| v.as_str().map(String::from))
+        .unwrap_or_default();
+    if enabled && token.is_empty() {
+        match server::generate_token() {
+            Ok(generated) => {
+                store.set("apiToken", serde_json::json!(generated));
+                let _ = store.save();
+                token = generated;
+            }
+            Err(e) => {
+                let _ = app.emit("api-error", e);
+                return;
+            }
+        }
+    }
+
+    let Ok(mut running) = API_SERVER.write() else {
+        return;
+    };
+    if enabled
+        && running
+            .as_ref()
+            .is_some_and(|server| server.port == port && server.token == token)
+    {
+        return;
+    }
+
+    if let Some(server) = running.take() {
+        server.stop();
+    }
+    if enabled {
+        match server::ApiServer::start(port, token, || CONTRIBUTION_CACHE.read().ok()?.clone()) {
+            Ok(server) => *running = Some(server),
+            Err(e) => {
+                let _ = app.emit("api-error", e);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Midnight Rollover
+// ============================================================================
+
+/// Recalculates streaks when the day changes in the user's timezone.
+///
+/// A streak that ended yesterday is still "current" today, but not
+/// tomorrow. Without this, the cached stats would keep showing a
+/// broken streak as current until the next fetch.
+///
+/// "Today" is taken in the timezone of the cached user, since the cache
+/// may still hold a previous user until the next fetch.
+///
+/// ```text
+/// Every 10 minutes:
+///         │
+///         ▼
+/// ┌───────────────────────────────────────────┐
+/// │ Has "today" changed in the user's zone?   │
+/// └───────────────────────────────────────────┘
+///         │
+///    Yes  │  No ──▶ Sleep
+///         ▼
+/// ┌───────────────────────────────────────────┐
+/// │ Recalculate cached stats                  │
+/// │ Emit "refresh-requested" to the frontend  │
+/// └───────────────────────────────────────────┘
+/// ```
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+fn spawn_midnight_rollover(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let current_timezone = |app: &AppHandle| {
+            let cached = CONTRIBUTION_CACHE
+                .read()
+                .ok()
+                .and_then(|cache| Some(cache.as_ref()?.user.username.clone()));
+            cached
+                .or_else(|| CURRENT_USERNAME.read().ok().and_then(|u| u.clone()))
+                .map(|username| load_timezone(app, &username))
+                .unwrap_or_default()
+        };
+
+        let mut last_date = CLOCK.today(&current_timezone(&app));
+
+        loop {
+            let timezone = current_timezone(&app);
+            let now = CLOCK.now();
+            let until_midnight = (timezone.next_midnight(now) - now)
+                .to_std()
+                .unwrap_or_default();
+
+            tokio::time::sleep(until_midnight.min(ROLLOVER_CHECK_INTERVAL)).await;
+
+            let today = CLOCK.today(&current_timezone(&app));
+            if today == last_date {
+                continue;
+            }
+            last_date = today;
+
+            let rules = load_streak_rules(&app);
+            if let Ok(mut cache) = CONTRIBUTION_CACHE.write() {
+                if let Some(data) = cache.as_mut() {
+                    github::recalculate_stats(data, today, &rules);
+                }
+            }
+
+            // Let the frontend pick up the new day (and fetch today's contributions)
+            let _ = app.emit("refresh-requested", ());
+        }
+    });
+}
+
 // ============================================================================
 // Application Entry Point
 // ============================================================================
@@ -640,6 +2037,7 @@ fn load_saved_username(app: &AppHandle) {
 /// │ 2. Setup Phase                                                  │
 /// │    - Create system tray icon                                    │
 /// │    - Load saved username                                        │
+/// │    - Start midnight rollover task                               │
 /// │    - Set activation policy (macOS: hide from dock)              │
 /// └─────────────────────────────────────────────────────────────────┘
 ///                              │
@@ -668,6 +2066,10 @@ pub fn run() {
             MacosLauncher::LaunchAgent,
             Some(vec!["--hidden"]), // Start hidden when launched at login
         ))
+        // Plugin: Native notifications (achievements)
+        .plugin(tauri_plugin_notification::init())
+        // Plugin: Clipboard (stats card)
+        .plugin(tauri_plugin_clipboard_manager::init())
         .setup(|app| {
             // Initialize system tray
             setup_tray(app.handle())?;
@@ -675,6 +2077,12 @@ pub fn run() {
             // Load saved username for session restoration
             load_saved_username(app.handle());
 
+            // Recalculate streaks when the day rolls over
+            spawn_midnight_rollover(app.handle().clone());
+
+            // Serve the cached data on 127.0.0.1 if enabled
+            configure_api_server(app.handle());
+
             // macOS: Run as accessory app (no dock icon)
             #[cfg(target_os = "macos")]
             {
@@ -688,8 +2096,24 @@ pub fn run() {
             fetch_contributions,
             get_cached_contributions,
             refresh_contributions,
+            get_stats_as_of,
+            get_streak_timeline,
+            get_streak_history,
+            get_top_streaks,
+            get_projections,
+            get_range_stats,
+            get_goals,
+            save_goals,
+            get_goal_progress,
+            get_achievements,
+            export_contributions,
+            export_heatmap_svg,
+            export_stats_card,
+            export_streak_badge,
+            import_contributions,
             get_settings,
             save_settings,
+            validate_username,
             hide_window,
             get_autostart_enabled,
             set_autostart_enabled,
@@ -0,0 +1,137 @@
+//! # Projections
+//!
+//! Looks ahead from the current streak and the recent daily rate:
+//! when the next streak milestone is reached, where the year will end,
+//! and what it takes to beat last year's total.
+//!
+//! ## Projection Math
+//!
+//! ```text
+//! Today: 2024-12-01, streak 25 days (ends today), 30-day rate 4.0
+//!
+//! Next milestone   30 days ──▶ 5 more days ──▶ 2024-12-06
+//! Year end         year-to-date + 4.0 × 30 remaining days
+//! Beat last year   (last year + 1 − year-to-date) / 30 remaining days
+//! ```
+//!
+//! Rest days are skipped when counting towards the next milestone,
+//! just like they are skipped by the relaxed streak.
+
+use crate::achievements::STREAK_MILESTONES;
+use crate::types::{ContributionDay, ContributionStats, Projections, StreakRules};
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// Calculates streak and year-end projections.
+///
+/// # Arguments
+///
+/// * `days` - Slice of contribution days (sorted chronologically)
+/// * `stats` - Statistics calculated as of `today`
+/// * `today` - Today's date in the user's timezone
+/// * `rules` - Streak rules (rest days) used for the relaxed streak
+/// * `last_year_total` - Last calendar year's total, if known
+///
+/// # Returns
+///
+/// Projections for the next streak milestone and the year end
+pub fn calculate_projections(
+    days: &[ContributionDay],
+    stats: &ContributionStats,
+    today: NaiveDate,
+    rules: &StreakRules,
+    last_year_total: Option<u32>,
+) -> Projections {
+    let streak = &stats.relaxed_current_streak;
+    let next_streak_milestone = STREAK_MILESTONES
+        .iter()
+        .copied()
+        .find(|days| *days > streak.count);
+
+    // Keep going from the streak's last day (or from today without a streak)
+    let next_milestone_date = next_streak_milestone.and_then(|milestone| {
+        let mut date = if streak.count > 0 {
+            NaiveDate::parse_from_str(&streak.end_date, "%Y-%m-%d").ok()?
+        } else {
+            today - Duration::days(1)
+        };
+        for _ in streak.count..milestone {
+            date = rules.next_working_day(date);
+        }
+        Some(date.format("%Y-%m-%d").to_string())
+    });
+
+    let year_to_date: u32 = days
+        .iter()
+        .filter(|day| {
+            NaiveDate::parse_from_str(&day.date, "%Y-%m-%d")
+                .map(|date| date.year() == today.year() && date <= today)
+                .unwrap_or(false)
+        })
+        .map(|day| day.count)
+        .sum();
+
+    let year_end = NaiveDate::from_ymd_opt(today.year(), 12, 31).unwrap_or(today);
+    let days_remaining = (year_end - today).num_days().max(0) as u32;
+    let daily_rate = stats.rolling_averages.last_30_days;
+
+    let needed_per_day = match last_year_total {
+        Some(last) if year_to_date > last => Some(0.0),
+        Some(_) if days_remaining == 0 => None,
+        Some(last) => Some((last + 1 - year_to_date) as f32 / days_remaining as f32),
+        None => None,
+    };
+
+    Projections {
+        current_streak: streak.count,
+        next_streak_milestone,
+        next_milestone_date,
+        daily_rate,
+        year_to_date,
+        days_remaining,
+        projected_year_end_total: year_to_date
+            + (daily_rate * days_remaining as f32).round() as u32,
+        last_year_total,
+        needed_per_day,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::calculate_stats;
+    use crate::test_support::days;
+
+    /// Tests the projections from the module diagram.
+    #[test]
+    fn test_projections() {
+        // 2024-11-01 .. 2024-12-01: 6 idle days, then a 25-day streak of 4
+        let counts: Vec<u32> = (0..31).map(|i| if i < 6 { 0 } else { 4 }).collect();
+        let days = days("2024-11-01", &counts);
+        let today = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+        let rules = StreakRules::default();
+        let stats = calculate_stats(&days, today, &rules);
+
+        let projections = calculate_projections(&days, &stats, today, &rules, Some(200));
+        assert_eq!(projections.current_streak, 25);
+        assert_eq!(projections.next_streak_milestone, Some(30));
+        assert_eq!(
+            projections.next_milestone_date.as_deref(),
+            Some("2024-12-06")
+        );
+        assert_eq!(projections.year_to_date, 100);
+        assert_eq!(projections.days_remaining, 30);
+
+        // 25 active days of the last 30 at 4 each
+        assert!((projections.daily_rate - 100.0 / 30.0).abs() < 0.01);
+        assert_eq!(projections.projected_year_end_total, 200);
+        assert!((projections.needed_per_day.unwrap() - 101.0 / 30.0).abs() < 0.01);
+
+        // Without last year's total there is nothing to beat
+        let unknown = calculate_projections(&days, &stats, today, &rules, None);
+        assert_eq!(unknown.needed_per_day, None);
+    }
+}
@@ -0,0 +1,202 @@
+//! # Streak Badge
+//!
+//! Renders a small SVG badge with the current streak, longest streak
+//! and total, for embedding in a GitHub profile README. The app writes
+//! it to the configured `badgePath` after every refresh, so a git hook
+//! or sync folder can publish it.
+//!
+//! Badge text is sized with `textLength`, so the layout doesn't depend
+//! on which fonts the viewer has installed.
+
+use crate::render::palette::Palette;
+use crate::render::svg::{group_thousands, FONT};
+use crate::types::{BadgeStyle, ContributionStats, RenderTheme};
+
+/// Height of the flat badges.
+const FLAT_HEIGHT: u32 = 20;
+
+/// Approximate width of one character at 11px.
+const CHAR_WIDTH: u32 = 7;
+
+/// Horizontal padding on each side of a flat badge segment.
+const SEGMENT_PADDING: u32 = 6;
+
+/// Width of one column of the card.
+const CARD_COLUMN: u32 = 110;
+
+/// Height of the card.
+const CARD_HEIGHT: u32 = 80;
+
+/// Renders the streak badge as an SVG document.
+///
+/// # Arguments
+///
+/// * `stats` - Statistics to show (relaxed current and longest streak, total)
+/// * `style` - Flat, flat-square or card layout
+/// * `theme` - Light or dark palette
+///
+/// # Returns
+///
+/// The complete SVG document
+pub fn render_streak_badge(
+    stats: &ContributionStats,
+    style: BadgeStyle,
+    theme: RenderTheme,
+) -> String {
+    let palette = Palette::for_theme(theme);
+    match style {
+        BadgeStyle::Flat => render_flat(stats, &palette, 3),
+        BadgeStyle::FlatSquare => render_flat(stats, &palette, 0),
+        BadgeStyle::Card => render_card(stats, &palette),
+    }
+}
+
+/// Shields-style badge: "streak" label and a one-line message.
+fn render_flat(stats: &ContributionStats, palette: &Palette, radius: u32) -> String {
+    let label = "streak";
+    let message = flat_message(stats);
+    let label_width = text_length(label) + 2 * SEGMENT_PADDING;
+    let message_width = text_length(&message) + 2 * SEGMENT_PADDING;
+    let width = label_width + message_width;
+
+    // A broken streak is shown in the muted color instead of green
+    let message_fill = if stats.relaxed_current_streak.count > 0 {
+        palette.level(3)
+    } else {
+        palette.muted
+    };
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+         role=\"img\" aria-label=\"{label}: {message}\">\n\
+         <title>{label}: {message}</title>\n\
+         <clipPath id=\"r\"><rect width=\"{w}\" height=\"{h}\" rx=\"{radius}\" fill=\"#fff\"/></clipPath>\n\
+         <g clip-path=\"url(#r)\">\n\
+         <rect width=\"{lw}\" height=\"{h}\" fill=\"{label_fill}\"/>\n\
+         <rect x=\"{lw}\" width=\"{mw}\" height=\"{h}\" fill=\"{message_fill}\"/>\n\
+         </g>\n\
+         <g text-anchor=\"middle\" font-family=\"{font}\" font-size=\"11\">\n\
+         <text x=\"{lx}\" y=\"14\" fill=\"{label_text}\" textLength=\"{ll}\">{label}</text>\n\
+         <text x=\"{mx}\" y=\"14\" fill=\"#ffffff\" textLength=\"{ml}\">{message}</text>\n\
+         </g>\n\
+         </svg>\n",
+        w = width,
+        h = FLAT_HEIGHT,
+        lw = label_width,
+        mw = message_width,
+        lx = label_width / 2,
+        mx = label_width + message_width / 2,
+        ll = text_length(label),
+        ml = text_length(&message),
+        label_fill = palette.text,
+        label_text = palette.background,
+        font = FONT,
+    )
+}
+
+/// Three-column card: total, current streak and longest streak.
+fn render_card(stats: &ContributionStats, palette: &Palette) -> String {
+    let width = 3 * CARD_COLUMN;
+    let columns = [
+        (
+            group_thousands(stats.total_contributions),
+            "Total",
+            palette.text,
+        ),
+        (
+            stats.relaxed_current_streak.count.to_string(),
+            "Current streak",
+            palette.level(4),
+        ),
+        (
+            stats.relaxed_longest_streak.count.to_string(),
+            "Longest streak",
+            palette.text,
+        ),
+    ];
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+         viewBox=\"0 0 {w} {h}\" font-family=\"{font}\" text-anchor=\"middle\" \
+         role=\"img\" aria-label=\"{aria}\">\n\
+         <title>{aria}</title>\n\
+         <rect x=\"0.5\" y=\"0.5\" width=\"{iw}\" height=\"{ih}\" rx=\"6\" \
+         fill=\"{bg}\" stroke=\"{border}\"/>\n",
+        w = width,
+        h = CARD_HEIGHT,
+        iw = width - 1,
+        ih = CARD_HEIGHT - 1,
+        font = FONT,
+        aria = flat_message(stats),
+        bg = palette.background,
+        border = palette.level(0),
+    );
+
+    for (i, (value, label, color)) in columns.iter().enumerate() {
+        let x = i as u32 * CARD_COLUMN + CARD_COLUMN / 2;
+        svg.push_str(&format!(
+            "<text x=\"{x}\" y=\"38\" font-size=\"22\" font-weight=\"600\" fill=\"{}\">{}</text>\n\
+             <text x=\"{x}\" y=\"60\" font-size=\"11\" fill=\"{}\">{}</text>\n",
+            color,
+            value,
+            palette.muted,
+            label,
+            x = x,
+        ));
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// "12 days · longest 45 · 1,234 total".
+fn flat_message(stats: &ContributionStats) -> String {
+    let current = stats.relaxed_current_streak.count;
+    let unit = if current == 1 { "day" } else { "days" };
+    format!(
+        "{} {} · longest {} · {} total",
+        current,
+        unit,
+        stats.relaxed_longest_streak.count,
+        group_thousands(stats.total_contributions)
+    )
+}
+
+/// Width reserved for `text` at 11px.
+fn text_length(text: &str) -> u32 {
+    text.chars().count() as u32 * CHAR_WIDTH
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{contribution_data, days};
+
+    /// Tests the flat message, segment widths and the card columns.
+    #[test]
+    fn test_render_streak_badge() {
+        // 1,200 contributions over the last three days
+        let stats = contribution_data(days("2024-03-01", &[400; 3])).stats;
+
+        let flat = render_streak_badge(&stats, BadgeStyle::Flat, RenderTheme::Light);
+        assert!(flat.contains("<title>streak: 3 days · longest 3 · 1,200 total</title>"));
+        assert!(flat.contains("rx=\"3\""));
+        // "streak" is 6 chars: 42px text + 12px padding
+        assert!(flat.contains("<rect width=\"54\" height=\"20\" fill=\"#1f2328\"/>"));
+        assert!(flat.contains("fill=\"#30a14e\""));
+
+        let square = render_streak_badge(&stats, BadgeStyle::FlatSquare, RenderTheme::Dark);
+        assert!(square.contains("rx=\"0\""));
+        assert!(square.contains("fill=\"#26a641\""));
+
+        let card = render_streak_badge(&stats, BadgeStyle::Card, RenderTheme::Light);
+        assert!(card.contains(">1,200</text>"));
+        assert!(card.contains("fill=\"#216e39\">3</text>"));
+        assert!(card.contains(">Longest streak</text>"));
+        assert!(card.ends_with("</svg>\n"));
+    }
+}
@@ -0,0 +1,27 @@
+//! # Render Module
+//!
+//! Turns contribution data into images that can be shared outside the
+//! app. Renderers are plain functions over the data, so they also work
+//! headlessly (no window or webview needed).
+//!
+//! ## Module Structure
+//!
+//! ```text
+//! render/
+//! ├── mod.rs     ◀── You are here (public exports)
+//! ├── badge.rs   ── README streak badge (flat, flat-square, card)
+//! ├── font.rs    ── 5×7 bitmap font for raster text
+//! ├── palette.rs ── GitHub light/dark colors
+//! ├── png.rs     ── PNG stats card (avatar, heatmap, streaks, total)
+//! └── svg.rs     ── SVG heatmap with labels, legend and stats footer
+//! ```
+
+mod badge;
+mod font;
+mod palette;
+mod png;
+mod svg;
+
+pub use badge::render_streak_badge;
+pub use png::render_stats_card;
+pub use svg::render_heatmap_svg;
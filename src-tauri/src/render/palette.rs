@@ -0,0 +1,53 @@
+//! # Render Palettes
+//!
+//! The GitHub colors used by every renderer, matching the CSS variables
+//! of the frontend (`--contrib-0` … `--contrib-4`).
+
+use crate::types::RenderTheme;
+
+/// Colors for one theme, as `#rrggbb` strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    /// Image background
+    pub background: &'static str,
+
+    /// Primary text (titles, numbers)
+    pub text: &'static str,
+
+    /// Secondary text (labels, legend)
+    pub muted: &'static str,
+
+    /// Cell colors for levels 0-4
+    pub levels: [&'static str; 5],
+}
+
+/// GitHub light palette.
+const LIGHT: Palette = Palette {
+    background: "#ffffff",
+    text: "#1f2328",
+    muted: "#656d76",
+    levels: ["#ebedf0", "#9be9a8", "#40c463", "#30a14e", "#216e39"],
+};
+
+/// GitHub dark palette.
+const DARK: Palette = Palette {
+    background: "#0d1117",
+    text: "#e6edf3",
+    muted: "#8b949e",
+    levels: ["#161b22", "#0e4429", "#006d32", "#26a641", "#39d353"],
+};
+
+impl Palette {
+    /// Returns the palette for a theme.
+    pub fn for_theme(theme: RenderTheme) -> Self {
+        match theme {
+            RenderTheme::Light => LIGHT,
+            RenderTheme::Dark => DARK,
+        }
+    }
+
+    /// Returns the cell color for a level, clamping anything above 4.
+    pub fn level(&self, level: u8) -> &'static str {
+        self.levels[usize::from(level.min(4))]
+    }
+}
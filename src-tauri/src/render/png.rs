@@ -0,0 +1,336 @@
+//! # PNG Stats Card
+//!
+//! Rasterizes a shareable summary card on the CPU with tiny-skia, so it
+//! works headlessly (no window, webview or GPU). Text uses the built-in
+//! bitmap font, so no system fonts are needed either.
+//!
+//! ## Layout
+//!
+//! ```text
+//! ╭──────────────────────────────────────────────╮
+//! │  ╭────╮                                      │
+//! │  │ 🐱 │  OCTOCAT                              │ ◀── Avatar, username
+//! │  ╰────╯                                      │
+//! │                                              │
+//! │  ■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■   │
+//! │  ■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■   │ ◀── Heatmap (7 rows)
+//! │  ...                                         │
+//! │                                              │
+//! │  12              45             1,234        │ ◀── Values
+//! │  CURRENT STREAK  LONGEST STREAK CONTRIBUTIONS│ ◀── Labels
+//! ╰──────────────────────────────────────────────╯
+//! ```
+
+use crate::render::font::{self, GLYPH_HEIGHT};
+use crate::render::palette::Palette;
+use crate::render::svg::group_thousands;
+use crate::types::{ContributionData, ContributionStats, RenderTheme};
+use base64::Engine;
+use chrono::{Datelike, NaiveDate};
+use image::imageops::FilterType;
+use tiny_skia::{
+    FillRule, IntSize, Mask, Paint, Path, PathBuilder, Pixmap, PixmapPaint, Rect, Transform,
+};
+
+/// Outer margin around the content.
+const MARGIN: u32 = 24;
+
+/// Avatar diameter in pixels.
+const AVATAR: u32 = 48;
+
+/// Size of a day cell in pixels.
+const CELL: u32 = 10;
+
+/// Distance between the top-left corners of neighboring cells.
+const STEP: u32 = 12;
+
+/// Top edge of the heatmap.
+const HEATMAP_TOP: u32 = 96;
+
+/// Top edge of the stat values, below the heatmap.
+const STATS_TOP: u32 = HEATMAP_TOP + 7 * STEP + 18;
+
+/// Minimum space between two stat columns.
+const COLUMN_GAP: u32 = 16;
+
+/// Corner radius of the card.
+const RADIUS: f32 = 12.0;
+
+/// Scale of the username and stat values.
+const LARGE: u32 = 3;
+
+/// Scale of the stat labels.
+const SMALL: u32 = 2;
+
+/// Renders the stats card as a PNG image.
+///
+/// # Arguments
+///
+/// * `data` - The cached contribution data (avatar, username, weeks, stats)
+/// * `theme` - Light or dark palette
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` - The encoded PNG
+/// * `Err(String)` - Error message if the image could not be created or encoded
+pub fn render_stats_card(data: &ContributionData, theme: RenderTheme) -> Result<Vec<u8>, String> {
+    let palette = Palette::for_theme(theme);
+    let username = &data.user.username;
+    let columns = data.weeks.len().max(1) as u32;
+    let entries = stat_entries(&data.stats);
+
+    let width = (2 * MARGIN + columns * STEP - (STEP - CELL))
+        .max(MARGIN + AVATAR + 16 + font::text_width(username, LARGE) + MARGIN)
+        .max(2 * MARGIN + 3 * min_column_width(&entries));
+    let height = STATS_TOP + (GLYPH_HEIGHT * LARGE) + 8 + (GLYPH_HEIGHT * SMALL) + MARGIN;
+
+    let mut pixmap = Pixmap::new(width, height).ok_or("Invalid card size")?;
+
+    // Rounded background on a transparent canvas
+    let background = rounded_rect(0.0, 0.0, width as f32, height as f32, RADIUS)
+        .ok_or("Failed to build card background")?;
+    pixmap.fill_path(
+        &background,
+        &paint(palette.background),
+        FillRule::Winding,
+        Transform::identity(),
+        None,
+    );
+
+    // Header: avatar and username
+    draw_avatar(&mut pixmap, data, &palette);
+    draw_text(
+        &mut pixmap,
+        username,
+        MARGIN + AVATAR + 16,
+        MARGIN + (AVATAR - GLYPH_HEIGHT * LARGE) / 2,
+        LARGE,
+        palette.text,
+    );
+
+    // Heatmap: one cell per day, positioned by weekday
+    for (column, week) in data.weeks.iter().enumerate() {
+        for day in &week.days {
+            let Ok(date) = NaiveDate::parse_from_str(&day.date, "%Y-%m-%d") else {
+                continue;
+            };
+            let row = date.weekday().num_days_from_sunday();
+            let x = MARGIN + column as u32 * STEP;
+            let y = HEATMAP_TOP + row * STEP;
+            if let Some(cell) = rounded_rect(x as f32, y as f32, CELL as f32, CELL as f32, 2.0) {
+                pixmap.fill_path(
+                    &cell,
+                    &paint(palette.level(day.level)),
+                    FillRule::Winding,
+                    Transform::identity(),
+                    None,
+                );
+            }
+        }
+    }
+
+    // Stats row: three equal columns
+    let column_width = (width - 2 * MARGIN) / 3;
+    for (i, (value, label)) in entries.iter().enumerate() {
+        let x = MARGIN + i as u32 * column_width;
+        draw_text(&mut pixmap, value, x, STATS_TOP, LARGE, palette.text);
+        draw_text(
+            &mut pixmap,
+            label,
+            x,
+            STATS_TOP + GLYPH_HEIGHT * LARGE + 8,
+            SMALL,
+            palette.muted,
+        );
+    }
+
+    pixmap
+        .encode_png()
+        .map_err(|e| format!("Failed to encode PNG: {}", e))
+}
+
+/// Values and labels of the three stat columns.
+fn stat_entries(stats: &ContributionStats) -> [(String, &'static str); 3] {
+    [
+        (
+            stats.relaxed_current_streak.count.to_string(),
+            "Current streak",
+        ),
+        (
+            stats.relaxed_longest_streak.count.to_string(),
+            "Longest streak",
+        ),
+        (group_thousands(stats.total_contributions), "Contributions"),
+    ]
+}
+
+/// Narrowest stat column that fits every value and label plus a gap.
+fn min_column_width(entries: &[(String, &str)]) -> u32 {
+    entries
+        .iter()
+        .map(|(value, label)| font::text_width(value, LARGE).max(font::text_width(label, SMALL)))
+        .max()
+        .unwrap_or(0)
+        + COLUMN_GAP
+}
+
+/// Draws the circular avatar, or a colored circle with the initial when
+/// no cached avatar is available or it can't be decoded.
+fn draw_avatar(pixmap: &mut Pixmap, data: &ContributionData, palette: &Palette) {
+    let radius = AVATAR as f32 / 2.0;
+    let center = MARGIN as f32 + radius;
+    let Some(circle) = PathBuilder::from_circle(center, center, radius) else {
+        return;
+    };
+
+    if let Some(avatar) = data.user.avatar_data_uri.as_deref().and_then(decode_avatar) {
+        if let Some(mut mask) = Mask::new(pixmap.width(), pixmap.height()) {
+            mask.fill_path(&circle, FillRule::Winding, true, Transform::identity());
+            pixmap.draw_pixmap(
+                MARGIN as i32,
+                MARGIN as i32,
+                avatar.as_ref(),
+                &PixmapPaint::default(),
+                Transform::identity(),
+                Some(&mask),
+            );
+            return;
+        }
+    }
+
+    pixmap.fill_path(
+        &circle,
+        &paint(palette.level(3)),
+        FillRule::Winding,
+        Transform::identity(),
+        None,
+    );
+    let initial: String = data.user.username.chars().take(1).collect();
+    let scale = 4;
+    draw_text(
+        pixmap,
+        &initial,
+        MARGIN + (AVATAR - font::text_width(&initial, scale)) / 2,
+        MARGIN + (AVATAR - GLYPH_HEIGHT * scale) / 2,
+        scale,
+        palette.background,
+    );
+}
+
+/// Decodes a `data:` URI into an avatar-sized, premultiplied pixmap.
+fn decode_avatar(data_uri: &str) -> Option<Pixmap> {
+    let (_, encoded) = data_uri.split_once(',')?;
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .ok()?;
+    let image = image::load_from_memory(&bytes)
+        .ok()?
+        .resize_exact(AVATAR, AVATAR, FilterType::Triangle)
+        .to_rgba8();
+
+    // tiny-skia stores premultiplied alpha
+    let mut pixels = image.into_raw();
+    for pixel in pixels.chunks_exact_mut(4) {
+        let alpha = u16::from(pixel[3]);
+        for channel in &mut pixel[..3] {
+            *channel = ((u16::from(*channel) * alpha + 127) / 255) as u8;
+        }
+    }
+
+    Pixmap::from_vec(pixels, IntSize::from_wh(AVATAR, AVATAR)?)
+}
+
+/// Draws `text` with the bitmap font, top-left corner at `(x, y)`.
+fn draw_text(pixmap: &mut Pixmap, text: &str, x: u32, y: u32, scale: u32, color: &str) {
+    let paint = paint(color);
+    for (dx, dy) in font::text_pixels(text, scale) {
+        if let Some(rect) =
+            Rect::from_xywh((x + dx) as f32, (y + dy) as f32, scale as f32, scale as f32)
+        {
+            pixmap.fill_rect(rect, &paint, Transform::identity(), None);
+        }
+    }
+}
+
+/// Builds a rectangle with rounded corners.
+fn rounded_rect(x: f32, y: f32, width: f32, height: f32, radius: f32) -> Option<Path> {
+    let (right, bottom) = (x + width, y + height);
+    let mut builder = PathBuilder::new();
+    builder.move_to(x + radius, y);
+    builder.line_to(right - radius, y);
+    builder.quad_to(right, y, right, y + radius);
+    builder.line_to(right, bottom - radius);
+    builder.quad_to(right, bottom, right - radius, bottom);
+    builder.line_to(x + radius, bottom);
+    builder.quad_to(x, bottom, x, bottom - radius);
+    builder.line_to(x, y + radius);
+    builder.quad_to(x, y, x + radius, y);
+    builder.close();
+    builder.finish()
+}
+
+/// Creates an anti-aliased solid paint from a `#rrggbb` color.
+fn paint(hex: &str) -> Paint<'static> {
+    let (r, g, b) = rgb(hex);
+    let mut paint = Paint::default();
+    paint.set_color_rgba8(r, g, b, 255);
+    paint
+}
+
+/// Parses a `#rrggbb` color, falling back to black.
+fn rgb(hex: &str) -> (u8, u8, u8) {
+    let channel = |i: usize| {
+        hex.get(i..i + 2)
+            .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+            .unwrap_or(0)
+    };
+    (channel(1), channel(3), channel(5))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{contribution_data, days};
+
+    /// Tests the card size, transparent corners, heatmap cells and the
+    /// fallback avatar.
+    #[test]
+    fn test_render_stats_card() {
+        // Sun 2024-03-03 .. Sat 2024-03-16: two full weeks
+        let data = contribution_data(days("2024-03-03", &[4; 14]));
+
+        let png = render_stats_card(&data, RenderTheme::Light).unwrap();
+        let image = image::load_from_memory(&png).unwrap().to_rgba8();
+        let entries = stat_entries(&data.stats);
+        assert_eq!(image.width(), 2 * MARGIN + 3 * min_column_width(&entries));
+        assert_eq!(image.height(), STATS_TOP + 21 + 8 + 14 + MARGIN);
+
+        // Short ranges are widened until no label runs into the next column
+        let column_width = (image.width() - 2 * MARGIN) / 3;
+        for (value, label) in &entries {
+            let text = font::text_width(value, LARGE).max(font::text_width(label, SMALL));
+            assert!(text + COLUMN_GAP <= column_width);
+        }
+
+        // Rounded corner is transparent, the center of the card is not
+        assert_eq!(image.get_pixel(0, 0)[3], 0);
+        assert_eq!(
+            image.get_pixel(image.width() / 2, 60).0,
+            [255, 255, 255, 255]
+        );
+
+        // Center of the first cell has the level-4 color (#216e39)
+        let cell = image.get_pixel(MARGIN + CELL / 2, HEATMAP_TOP + CELL / 2);
+        assert_eq!(cell.0, [0x21, 0x6e, 0x39, 255]);
+
+        // Fallback avatar circle uses the level-3 color (#30a14e)
+        let avatar = image.get_pixel(MARGIN + 4, MARGIN + AVATAR / 2);
+        assert_eq!(avatar.0, [0x30, 0xa1, 0x4e, 255]);
+
+        assert_eq!(font::text_width("abc", 2), 34);
+    }
+}
@@ -0,0 +1,319 @@
+//! # SVG Heatmap
+//!
+//! Renders the contribution calendar as a standalone SVG, for READMEs
+//! and slides. Works on the weeks from `group_into_weeks`, so it needs
+//! no window or webview.
+//!
+//! ## Layout
+//!
+//! ```text
+//!         Mar           Apr           May            ◀── Month labels
+//!       ┌──┐┌──┐┌──┐┌──┐┌──┐┌──┐┌──┐┌──┐┌──┐
+//!       │  ││  ││  ││  ││  ││  ││  ││  ││  │          Sun
+//!  Mon  │  ││  ││  ││  ││  ││  ││  ││  ││  │
+//!       │  ││  ││  ││  ││  ││  ││  ││  ││  │          ...
+//!  Wed  │  ││  ││  ││  ││  ││  ││  ││  ││  │
+//!       ...  one column per week, one row per weekday
+//!
+//!  1,234 contributions · ...             Less ■■■■■ More   ◀── Footer, legend
+//! ```
+
+use crate::render::palette::Palette;
+use crate::types::{ContributionStats, ContributionWeek, RenderTheme};
+use chrono::{Datelike, NaiveDate};
+
+/// Size of a day cell in pixels.
+const CELL: u32 = 10;
+
+/// Distance between the top-left corners of neighboring cells.
+const STEP: u32 = 13;
+
+/// Space for the weekday labels on the left.
+const LEFT: u32 = 30;
+
+/// Space for the month labels on the top.
+const TOP: u32 = 20;
+
+/// Outer padding on the right and bottom.
+const PADDING: u32 = 10;
+
+/// Height of the legend and footer row.
+const FOOTER: u32 = 24;
+
+/// Minimum width that fits the legend.
+const MIN_WIDTH: u32 = 180;
+
+/// Approximate width of one character at 9px.
+///
+/// The footer is drawn with `textLength` set to this estimate, so the
+/// reserved space doesn't depend on the viewer's fonts.
+const CHAR_WIDTH: u32 = 6;
+
+/// Width of the "More" label right of the legend cells.
+const MORE_WIDTH: u32 = 28;
+
+/// Width of the legend: "Less", its gap, five cells and "More".
+const LEGEND_WIDTH: u32 = 4 * CHAR_WIDTH + 4 + 5 * STEP + MORE_WIDTH;
+
+/// Minimum space between the stats footer and the legend.
+const FOOTER_GAP: u32 = 16;
+
+/// Font stack used for every label.
+pub(super) const FONT: &str =
+    "-apple-system, BlinkMacSystemFont, 'Segoe UI', Helvetica, Arial, sans-serif";
+
+/// Short month names, January first.
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Renders the heatmap as an SVG document.
+///
+/// # Arguments
+///
+/// * `weeks` - Weeks from `group_into_weeks` (Sunday-Saturday columns)
+/// * `theme` - Light or dark palette
+/// * `stats` - Statistics for the footer; `None` omits it
+///
+/// # Returns
+///
+/// The complete SVG document
+pub fn render_heatmap_svg(
+    weeks: &[ContributionWeek],
+    theme: RenderTheme,
+    stats: Option<&ContributionStats>,
+) -> String {
+    let palette = Palette::for_theme(theme);
+    let columns = weeks.len().max(1) as u32;
+    let footer = stats.map(footer_text);
+    // Reserve room for the footer and the legend side by side
+    let min_width = match footer.as_deref() {
+        Some(footer) => LEFT + text_length(footer) + FOOTER_GAP + LEGEND_WIDTH + PADDING,
+        None => MIN_WIDTH,
+    };
+    let width = (LEFT + columns * STEP + PADDING).max(min_width);
+    let grid_bottom = TOP + 7 * STEP;
+    let height = grid_bottom + FOOTER + PADDING;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+         viewBox=\"0 0 {w} {h}\" font-family=\"{font}\" font-size=\"9\">\n\
+         <rect width=\"{w}\" height=\"{h}\" fill=\"{bg}\"/>\n",
+        w = width,
+        h = height,
+        font = FONT,
+        bg = palette.background,
+    );
+
+    // Month labels above the first week of each month
+    for (column, month) in month_labels(weeks) {
+        svg.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\" fill=\"{}\">{}</text>\n",
+            LEFT + column * STEP,
+            TOP - 7,
+            palette.muted,
+            MONTHS[month as usize - 1]
+        ));
+    }
+
+    // Weekday labels like GitHub: Mon, Wed, Fri
+    for (row, name) in [(1, "Mon"), (3, "Wed"), (5, "Fri")] {
+        svg.push_str(&format!(
+            "<text x=\"0\" y=\"{}\" fill=\"{}\">{}</text>\n",
+            TOP + row * STEP + CELL - 1,
+            palette.muted,
+            name
+        ));
+    }
+
+    // One cell per day, positioned by weekday
+    for (column, week) in weeks.iter().enumerate() {
+        for day in &week.days {
+            let Ok(date) = NaiveDate::parse_from_str(&day.date, "%Y-%m-%d") else {
+                continue;
+            };
+            let row = date.weekday().num_days_from_sunday();
+            svg.push_str(&format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{cell}\" height=\"{cell}\" rx=\"2\" fill=\"{}\">\
+                 <title>{} on {}</title></rect>\n",
+                LEFT + column as u32 * STEP,
+                TOP + row * STEP,
+                palette.level(day.level),
+                contributions(day.count),
+                day.date,
+                cell = CELL,
+            ));
+        }
+    }
+
+    // Legend in the bottom-right corner
+    let baseline = grid_bottom + 14;
+    let legend_x = legend_x(width);
+    svg.push_str(&format!(
+        "<text x=\"{}\" y=\"{}\" fill=\"{}\" text-anchor=\"end\">Less</text>\n",
+        legend_x - 4,
+        baseline,
+        palette.muted
+    ));
+    for (i, color) in palette.levels.iter().enumerate() {
+        svg.push_str(&format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{cell}\" height=\"{cell}\" rx=\"2\" fill=\"{}\"/>\n",
+            legend_x + i as u32 * STEP,
+            baseline - CELL + 1,
+            color,
+            cell = CELL,
+        ));
+    }
+    svg.push_str(&format!(
+        "<text x=\"{}\" y=\"{}\" fill=\"{}\">More</text>\n",
+        legend_x + 5 * STEP + 1,
+        baseline,
+        palette.muted
+    ));
+
+    // Optional stats footer in the bottom-left corner
+    if let Some(footer) = footer {
+        svg.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\" fill=\"{}\" textLength=\"{}\">{}</text>\n",
+            LEFT,
+            baseline,
+            palette.text,
+            text_length(&footer),
+            footer
+        ));
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// X position of the first legend cell for an image `width` wide.
+fn legend_x(width: u32) -> u32 {
+    width - PADDING - 5 * STEP - MORE_WIDTH
+}
+
+/// Estimated width of a label at 9px.
+fn text_length(text: &str) -> u32 {
+    text.chars().count() as u32 * CHAR_WIDTH
+}
+
+/// Returns `(column, month)` pairs for the month labels.
+///
+/// A column is labeled when its first day starts a new month. The very
+/// first label is dropped if the next one follows within two columns,
+/// so partial months at the start don't overlap.
+fn month_labels(weeks: &[ContributionWeek]) -> Vec<(u32, u32)> {
+    let mut labels: Vec<(u32, u32)> = Vec::new();
+    let mut previous = None;
+
+    for (column, week) in weeks.iter().enumerate() {
+        let Some(date) = week
+            .days
+            .first()
+            .and_then(|day| NaiveDate::parse_from_str(&day.date, "%Y-%m-%d").ok())
+        else {
+            continue;
+        };
+        if previous != Some(date.month()) {
+            labels.push((column as u32, date.month()));
+            previous = Some(date.month());
+        }
+    }
+
+    if labels.len() > 1 && labels[1].0 - labels[0].0 < 3 {
+        labels.remove(0);
+    }
+    labels
+}
+
+/// Formats a count with thousands separators ("12,345").
+pub(super) fn group_thousands(value: u32) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        // A separator goes before every third digit counted from the end
+        if i > 0 && i % 3 == digits.len() % 3 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// "1 contribution" / "1,234 contributions".
+fn contributions(count: u32) -> String {
+    let unit = if count == 1 {
+        "contribution"
+    } else {
+        "contributions"
+    };
+    format!("{} {}", group_thousands(count), unit)
+}
+
+/// The footer line: total, current and longest streak.
+fn footer_text(stats: &ContributionStats) -> String {
+    let days = |count: u32| if count == 1 { "day" } else { "days" };
+    let current = stats.relaxed_current_streak.count;
+    let longest = stats.relaxed_longest_streak.count;
+
+    format!(
+        "{} · Current streak {} {} · Longest streak {} {}",
+        contributions(stats.total_contributions),
+        current,
+        days(current),
+        longest,
+        days(longest)
+    )
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{contribution_data, days};
+
+    /// Tests the SVG structure: one cell per day, labels, legend and footer.
+    #[test]
+    fn test_render_heatmap_svg() {
+        // Sun 2024-02-25 .. Sat 2024-03-16: three full weeks
+        let counts: Vec<u32> = NaiveDate::from_ymd_opt(2024, 2, 25)
+            .unwrap()
+            .iter_days()
+            .take(21)
+            .map(|date| date.day() % 5)
+            .collect();
+        let data = contribution_data(days("2024-02-25", &counts));
+        let (weeks, stats) = (data.weeks, data.stats);
+
+        let svg = render_heatmap_svg(&weeks, RenderTheme::Dark, Some(&stats));
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<title>").count(), 21);
+        assert!(svg.contains("<title>1 contribution on 2024-03-01</title>"));
+        assert!(svg.contains("fill=\"#0d1117\""));
+
+        // "Feb" is dropped: "Mar" follows in the next column
+        assert!(!svg.contains(">Feb<"));
+        assert!(svg.contains(">Mar<"));
+        assert!(svg.contains(">Less<") && svg.contains(">More<"));
+        assert!(svg.contains("contributions · Current streak"));
+
+        // The footer ends before "Less" starts, even for three weeks
+        let width: u32 = svg["<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"".len()..]
+            .split('"')
+            .next()
+            .unwrap()
+            .parse()
+            .unwrap();
+        let footer_end = LEFT + text_length(&footer_text(&stats));
+        let less_start = legend_x(width) - 4 - text_length("Less");
+        assert!(footer_end + FOOTER_GAP <= less_start);
+
+        let plain = render_heatmap_svg(&weeks, RenderTheme::Light, None);
+        assert!(!plain.contains("Current streak"));
+        assert_eq!(group_thousands(1234567), "1,234,567");
+    }
+}
@@ -0,0 +1,400 @@
+//! # Local HTTP API
+//!
+//! An opt-in HTTP server on `127.0.0.1` that serves the cached data to
+//! status bars and dashboards, so they don't have to scrape GitHub
+//! themselves. It never fetches; it only reads the cache.
+//!
+//! Web pages open in the user's browser can reach `127.0.0.1` too, so
+//! every request needs the bearer token (generated when the API is
+//! first enabled), responses carry no CORS headers, and requests whose
+//! `Host` isn't `127.0.0.1:{port}` or `localhost:{port}` are rejected
+//! to defeat DNS rebinding.
+//!
+//! ## Endpoints
+//!
+//! ```text
+//! GET /api/contributions   ── ContributionData (JSON)
+//! GET /api/stats           ── ContributionStats (JSON)
+//! GET /api/heatmap.svg     ── SVG heatmap      ?theme=light|dark&stats=true|false
+//! GET /api/badge.svg       ── SVG streak badge ?style=flat|flat-square|card&theme=light|dark
+//! GET /api/card.png        ── PNG stats card   ?theme=light|dark
+//! ```
+//!
+//! ## Request Handling
+//!
+//! ```text
+//! Request ──▶ GET? ──no──▶ 405
+//!               │ yes
+//!               ▼
+//!          Local Host header? ──no──▶ 403
+//!               │ yes
+//!               ▼
+//!          "Authorization: Bearer <token>"? ──no──▶ 401
+//!               │ yes
+//!               ▼
+//!          Route by path ──▶ 404 unknown path
+//!               │
+//!               ▼
+//!          Cached data? ──no──▶ 503
+//!               │ yes
+//!               ▼
+//!          200 JSON / SVG / PNG
+//! ```
+
+use crate::render;
+use crate::types::{BadgeStyle, ContributionData, RenderTheme};
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use tiny_http::{Header, Response, Server};
+
+/// A response produced by `handle_request`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiResponse {
+    /// HTTP status code
+    pub status: u16,
+
+    /// Value of the Content-Type header
+    pub content_type: &'static str,
+
+    /// Response body
+    pub body: Vec<u8>,
+}
+
+impl ApiResponse {
+    /// A JSON response.
+    fn json(status: u16, value: &impl serde::Serialize) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: serde_json::to_vec(value).unwrap_or_default(),
+        }
+    }
+
+    /// A JSON error response: `{"error": "..."}`.
+    fn error(status: u16, message: &str) -> Self {
+        Self::json(status, &serde_json::json!({ "error": message }))
+    }
+}
+
+/// Answers one request.
+///
+/// Kept free of any I/O, so the routing can be tested without sockets.
+///
+/// # Arguments
+///
+/// * `method` - HTTP method ("GET", "POST", …)
+/// * `url` - Path and query string ("/api/badge.svg?style=card")
+/// * `host` - Value of the Host header, if any
+/// * `authorization` - Value of the Authorization header, if any
+/// * `port` - Port the server listens on
+/// * `token` - Required bearer token; an empty token rejects every request
+/// * `data` - The cached contribution data, if any
+pub fn handle_request(
+    method: &str,
+    url: &str,
+    host: Option<&str>,
+    authorization: Option<&str>,
+    port: u16,
+    token: &str,
+    data: Option<&ContributionData>,
+) -> ApiResponse {
+    if method != "GET" {
+        return ApiResponse::error(405, "Only GET is supported");
+    }
+
+    // A rebound DNS name still carries the attacker's host name
+    if !host.is_some_and(|host| is_local_host(host, port)) {
+        return ApiResponse::error(403, "Host must be 127.0.0.1 or localhost");
+    }
+
+    let provided = authorization.and_then(|value| value.strip_prefix("Bearer "));
+    if token.is_empty() || !provided.is_some_and(|provided| tokens_match(provided.trim(), token)) {
+        return ApiResponse::error(401, "Missing or invalid bearer token");
+    }
+
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let known = [
+        "/api/contributions",
+        "/api/stats",
+        "/api/heatmap.svg",
+        "/api/badge.svg",
+        "/api/card.png",
+    ];
+    if !known.contains(&path) {
+        return ApiResponse::error(404, "Not found");
+    }
+    let Some(data) = data else {
+        return ApiResponse::error(503, "No contribution data fetched yet");
+    };
+
+    match route(path, query, data) {
+        Ok(response) => response,
+        Err(message) => ApiResponse::error(400, &message),
+    }
+}
+
+/// Renders a known endpoint for the cached data.
+fn route(path: &str, query: &str, data: &ContributionData) -> Result<ApiResponse, String> {
+    let theme: RenderTheme = query_param(query, "theme")?.unwrap_or_default();
+
+    let response = match path {
+        "/api/contributions" => ApiResponse::json(200, data),
+        "/api/stats" => ApiResponse::json(200, &data.stats),
+        "/api/heatmap.svg" => {
+            let stats = query_param(query, "stats")?
+                .unwrap_or(true)
+                .then_some(&data.stats);
+            svg(render::render_heatmap_svg(&data.weeks, theme, stats))
+        }
+        "/api/badge.svg" => {
+            let style: BadgeStyle = query_param(query, "style")?.unwrap_or_default();
+            svg(render::render_streak_badge(&data.stats, style, theme))
+        }
+        _ => ApiResponse {
+            status: 200,
+            content_type: "image/png",
+            body: render::render_stats_card(data, theme)?,
+        },
+    };
+    Ok(response)
+}
+
+/// An SVG response.
+fn svg(document: String) -> ApiResponse {
+    ApiResponse {
+        status: 200,
+        content_type: "image/svg+xml",
+        body: document.into_bytes(),
+    }
+}
+
+/// Parses a query parameter with the serde names of its type
+/// ("dark", "flat-square", "false").
+fn query_param<T: DeserializeOwned>(query: &str, name: &str) -> Result<Option<T>, String> {
+    let Some(value) = query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value)
+    else {
+        return Ok(None);
+    };
+
+    let parsed = serde_json::from_str(value)
+        .or_else(|_| serde_json::from_value(serde_json::Value::String(value.to_string())));
+    parsed
+        .map(Some)
+        .map_err(|_| format!("Invalid value for {}: \"{}\"", name, value))
+}
+
+/// Whether a Host header names this server on the loopback interface.
+fn is_local_host(host: &str, port: u16) -> bool {
+    let host = host.trim().to_ascii_lowercase();
+    [format!("127.0.0.1:{}", port), format!("localhost:{}", port)].contains(&host)
+}
+
+/// Generates a random bearer token (32 bytes, hex encoded).
+///
+/// # Returns
+///
+/// * `Ok(String)` - A 64-character token
+/// * `Err(String)` - Error message if the system has no randomness source
+pub fn generate_token() -> Result<String, String> {
+    let mut bytes = [0u8; 32];
+    getrandom::fill(&mut bytes).map_err(|e| format!("Failed to generate API token: {}", e))?;
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+/// Value of a request header, if present.
+fn request_header(request: &tiny_http::Request, name: &'static str) -> Option<String> {
+    request
+        .headers()
+        .iter()
+        .find(|header| header.field.equiv(name))
+        .map(|header| header.value.as_str().to_string())
+}
+
+/// Compares tokens without exiting early on the first difference.
+fn tokens_match(provided: &str, expected: &str) -> bool {
+    provided.len() == expected.len()
+        && provided
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+// ============================================================================
+// Server
+// ============================================================================
+
+/// A running API server and the settings it was started with.
+pub struct ApiServer {
+    /// Port the server listens on
+    pub port: u16,
+
+    /// Required bearer token
+    pub token: String,
+
+    /// The listener, shared with the request thread
+    server: Arc<Server>,
+
+    /// Thread answering requests until the server is unblocked
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ApiServer {
+    /// Starts listening on `127.0.0.1:{port}` on a background thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port to listen on
+    /// * `token` - Required bearer token (see `generate_token`)
+    /// * `data` - Returns the cached data for each request
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ApiServer)` - The running server
+    /// * `Err(String)` - Error message if the token is empty or the port can't be bound
+    pub fn start(
+        port: u16,
+        token: String,
+        data: fn() -> Option<ContributionData>,
+    ) -> Result<Self, String> {
+        if token.is_empty() {
+            return Err("The local HTTP API needs a bearer token".to_string());
+        }
+
+        let server = Server::http(("127.0.0.1", port))
+            .map_err(|e| format!("Failed to listen on port {}: {}", port, e))?;
+        let server = Arc::new(server);
+
+        let listener = Arc::clone(&server);
+        let required = token.clone();
+        let thread = std::thread::spawn(move || {
+            // Ends when `stop` unblocks the server
+            for request in listener.incoming_requests() {
+                let host = request_header(&request, "Host");
+                let authorization = request_header(&request, "Authorization");
+                let response = handle_request(
+                    request.method().as_str(),
+                    request.url(),
+                    host.as_deref(),
+                    authorization.as_deref(),
+                    port,
+                    &required,
+                    data().as_ref(),
+                );
+
+                // No CORS headers: other origins must not read the responses
+                let reply = Response::from_data(response.body)
+                    .with_status_code(response.status)
+                    .with_header(header("Content-Type", response.content_type));
+                let _ = request.respond(reply);
+            }
+        });
+
+        Ok(Self {
+            port,
+            token,
+            server,
+            thread: Some(thread),
+        })
+    }
+
+    /// Stops the server and waits for the listener thread to finish.
+    pub fn stop(mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Builds a response header from ASCII names and values.
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name.as_bytes(), value.as_bytes()).expect("ASCII header")
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{contribution_data, days};
+
+    /// Tests authentication, routing, query parameters and content types.
+    #[test]
+    fn test_handle_request() {
+        // Sun 2024-03-03 .. Sat 2024-03-09
+        let data = contribution_data(days("2024-03-03", &[2; 7]));
+        let host = Some("127.0.0.1:7878");
+        let auth = Some("Bearer s3cret");
+        let get = |url: &str| handle_request("GET", url, host, auth, 7878, "s3cret", Some(&data));
+
+        // Every request needs the token, even when none is configured
+        let request = |auth: Option<&str>, token: &str| {
+            handle_request("GET", "/api/stats", host, auth, 7878, token, Some(&data)).status
+        };
+        assert_eq!(request(None, "s3cret"), 401);
+        assert_eq!(request(Some("Bearer wrong!"), "s3cret"), 401);
+        assert_eq!(request(None, ""), 401);
+
+        // Foreign hosts (DNS rebinding) and other ports are rejected
+        let from_host = |host: Option<&str>| {
+            handle_request("GET", "/api/stats", host, auth, 7878, "s3cret", Some(&data)).status
+        };
+        assert_eq!(from_host(Some("evil.example:7878")), 403);
+        assert_eq!(from_host(Some("127.0.0.1:8080")), 403);
+        assert_eq!(from_host(None), 403);
+        assert_eq!(from_host(Some("LocalHost:7878")), 200);
+
+        let stats = get("/api/stats");
+        assert_eq!(
+            (stats.status, stats.content_type),
+            (200, "application/json")
+        );
+        let json: serde_json::Value = serde_json::from_slice(&stats.body).unwrap();
+        assert_eq!(json["totalContributions"], 14);
+
+        let badge = get("/api/badge.svg?style=card&theme=dark");
+        assert_eq!((badge.status, badge.content_type), (200, "image/svg+xml"));
+        assert!(String::from_utf8(badge.body).unwrap().contains("#0d1117"));
+
+        let heatmap = get("/api/heatmap.svg?stats=false");
+        assert_eq!(heatmap.status, 200);
+        assert!(!String::from_utf8(heatmap.body)
+            .unwrap()
+            .contains("Current streak"));
+
+        let card = get("/api/card.png");
+        assert_eq!((card.status, card.content_type), (200, "image/png"));
+
+        assert_eq!(get("/api/badge.svg?style=round").status, 400);
+        assert_eq!(get("/api/unknown").status, 404);
+        assert_eq!(
+            handle_request(
+                "POST",
+                "/api/stats",
+                host,
+                auth,
+                7878,
+                "s3cret",
+                Some(&data)
+            )
+            .status,
+            405
+        );
+        assert_eq!(
+            handle_request("GET", "/api/stats", host, auth, 7878, "s3cret", None).status,
+            503
+        );
+
+        let token = generate_token().unwrap();
+        assert_eq!(token.len(), 64);
+        assert_ne!(token, generate_token().unwrap());
+    }
+}
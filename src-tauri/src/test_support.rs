@@ -0,0 +1,52 @@
+//! # Test Fixtures
+//!
+//! Builders for the contribution data used across the unit tests, so
+//! each test only states the counts it cares about.
+
+use crate::github::{calculate_stats, group_into_weeks};
+use crate::types::{ContributionData, ContributionDay, StreakRules, UserInfo};
+use chrono::NaiveDate;
+
+/// Consecutive days starting at `start`, one per count.
+///
+/// Levels follow the counts (capped at 4), so rendered cells differ
+/// without running the level scale.
+///
+/// # Arguments
+///
+/// * `start` - First date (YYYY-MM-DD)
+/// * `counts` - Contribution count of each day
+pub fn days(start: &str, counts: &[u32]) -> Vec<ContributionDay> {
+    let start = NaiveDate::parse_from_str(start, "%Y-%m-%d").unwrap();
+    start
+        .iter_days()
+        .zip(counts)
+        .map(|(date, count)| ContributionDay {
+            date: date.format("%Y-%m-%d").to_string(),
+            count: *count,
+            level: (*count).min(4) as u8,
+        })
+        .collect()
+}
+
+/// Contribution data for "octocat" with stats as of the last day.
+///
+/// # Arguments
+///
+/// * `days` - The days of the calendar, oldest first
+pub fn contribution_data(days: Vec<ContributionDay>) -> ContributionData {
+    let today = days
+        .last()
+        .and_then(|day| NaiveDate::parse_from_str(&day.date, "%Y-%m-%d").ok())
+        .unwrap();
+    ContributionData {
+        user: UserInfo {
+            username: "octocat".to_string(),
+            ..Default::default()
+        },
+        stats: calculate_stats(&days, today, &StreakRules::default()),
+        weeks: group_into_weeks(days),
+        private_contributions: Default::default(),
+        last_updated: String::new(),
+    }
+}
@@ -0,0 +1,140 @@
+//! # Timezone Handling
+//!
+//! GitHub buckets contributions into calendar days using the profile's
+//! timezone, not the timezone of the machine running Git Streaks. This
+//! module decides what "today" is for a tracked user so streaks don't
+//! break when travelling or when tracking someone in another zone.
+//!
+//! ## Example
+//!
+//! ```text
+//! Instant: 2024-03-10 02:00 UTC
+//!
+//! Zone                  Today
+//! ─────────────────────────────────
+//! Europe/Berlin         2024-03-10
+//! America/Los_Angeles   2024-03-09   ◀── Still "yesterday" in LA
+//! ```
+//!
+//! Zones are stored per tracked user in `Settings.timezones` as IANA
+//! names. Users without an entry (or with `"system"`) follow the
+//! system timezone.
+
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// Settings value meaning "use the system timezone".
+pub const SYSTEM_TIMEZONE: &str = "system";
+
+/// The timezone used to decide which calendar day it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UserTimezone {
+    /// Follow the timezone of this machine
+    #[default]
+    System,
+
+    /// A named IANA timezone (e.g. "Europe/Berlin")
+    Named(Tz),
+}
+
+impl UserTimezone {
+    /// Parses a timezone setting.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - An IANA timezone name, or `""`/`"system"` for the system zone
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(UserTimezone)` - The parsed timezone
+    /// * `Err(String)` - Error if the name isn't a known IANA timezone
+    pub fn parse(name: &str) -> Result<Self, String> {
+        let name = name.trim();
+        if name.is_empty() || name.eq_ignore_ascii_case(SYSTEM_TIMEZONE) {
+            return Ok(UserTimezone::System);
+        }
+
+        name.parse::<Tz>()
+            .map(UserTimezone::Named)
+            .map_err(|_| format!("Unknown timezone \"{}\"", name))
+    }
+
+    /// Returns the calendar date of an instant in this timezone.
+    pub fn date_of(&self, instant: DateTime<Utc>) -> NaiveDate {
+        match self {
+            UserTimezone::System => instant.with_timezone(&Local).date_naive(),
+            UserTimezone::Named(tz) => instant.with_timezone(tz).date_naive(),
+        }
+    }
+
+    /// Returns the first instant of the day after `instant` in this timezone.
+    ///
+    /// Used to recalculate streaks when the day rolls over. Around DST
+    /// transitions where midnight doesn't exist, the earliest valid
+    /// time of the next day is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `instant` - The current instant
+    ///
+    /// # Returns
+    ///
+    /// The UTC instant of the next local midnight
+    pub fn next_midnight(&self, instant: DateTime<Utc>) -> DateTime<Utc> {
+        let tomorrow = self.date_of(instant) + Duration::days(1);
+        let fallback = instant + Duration::days(1);
+
+        // Midnight may be skipped by DST; walk forward to the first valid hour
+        (0..24)
+            .filter_map(|hour| tomorrow.and_hms_opt(hour, 0, 0))
+            .find_map(|local| match self {
+                UserTimezone::System => Local
+                    .from_local_datetime(&local)
+                    .earliest()
+                    .map(|dt| dt.with_timezone(&Utc)),
+                UserTimezone::Named(tz) => tz
+                    .from_local_datetime(&local)
+                    .earliest()
+                    .map(|dt| dt.with_timezone(&Utc)),
+            })
+            .unwrap_or(fallback)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests parsing timezone settings.
+    #[test]
+    fn test_parse_timezone() {
+        assert_eq!(UserTimezone::parse("").unwrap(), UserTimezone::System);
+        assert_eq!(UserTimezone::parse("system").unwrap(), UserTimezone::System);
+        assert_eq!(
+            UserTimezone::parse("Europe/Berlin").unwrap(),
+            UserTimezone::Named(chrono_tz::Europe::Berlin)
+        );
+        assert!(UserTimezone::parse("Mars/Olympus_Mons").is_err());
+    }
+
+    /// Tests that the same instant falls on different days in different zones.
+    #[test]
+    fn test_date_of_and_next_midnight() {
+        let instant = Utc.with_ymd_and_hms(2024, 3, 10, 2, 0, 0).unwrap();
+        let berlin = UserTimezone::parse("Europe/Berlin").unwrap();
+        let los_angeles = UserTimezone::parse("America/Los_Angeles").unwrap();
+
+        assert_eq!(berlin.date_of(instant).to_string(), "2024-03-10");
+        assert_eq!(los_angeles.date_of(instant).to_string(), "2024-03-09");
+
+        // Midnight in LA on March 10 is 08:00 UTC (still PST)
+        assert_eq!(
+            los_angeles.next_midnight(instant),
+            Utc.with_ymd_and_hms(2024, 3, 10, 8, 0, 0).unwrap()
+        );
+    }
+}
@@ -9,7 +9,8 @@
 //! ContributionData
 //! ├── user: UserInfo
 //! │   ├── username: String
-//! │   └── avatar_url: String
+//! │   ├── avatar_url: String
+//! │   └── display_name, bio, company, followers, ... (profile)
 //! │
 //! ├── weeks: Vec<ContributionWeek>
 //! │   └── days: Vec<ContributionDay>
@@ -22,7 +23,13 @@
 //! │   ├── best_day: BestDay
 //! │   ├── average_per_day: f32
 //! │   ├── current_streak: Streak
-//! │   └── longest_streak: Streak
+//! │   ├── longest_streak: Streak
+//! │   ├── reported_total: Option<u32>
+//! │   └── total_is_estimate: bool
+//! │
+//! ├── private_contributions: PrivateContributions
+//! │   ├── included: bool
+//! │   └── count: Option<u32>
 //! │
 //! └── last_updated: String (ISO 8601)
 //! ```
@@ -39,7 +46,9 @@
 //! Level 4: ██ High (10+ contributions)
 //! ```
 
+use chrono::{Datelike, Duration, NaiveDate};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 // ============================================================================
 // Contribution Day
@@ -139,6 +148,390 @@ pub struct Streak {
     pub end_date: String,
 }
 
+// ============================================================================
+// Streak Timeline
+// ============================================================================
+
+/// The streak values on a single date, as if stats were calculated that day.
+///
+/// A list of these replays how a streak grew and broke over time.
+///
+/// # Fields
+///
+/// * `date` - The date (YYYY-MM-DD)
+/// * `current_streak` - Current streak length on that date
+/// * `longest_streak` - Longest streak achieved up to that date
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakTimelinePoint {
+    /// Date of this point (YYYY-MM-DD)
+    pub date: String,
+    
+    /// Current streak length on this date
+    pub current_streak: u32,
+    
+    /// Longest streak achieved up to this date
+    pub longest_streak: u32,
+}
+
+// ============================================================================
+// Streak History
+// ============================================================================
+
+/// A single streak from the contribution history.
+///
+/// # Fields
+///
+/// * `count` - Number of streak days
+/// * `start_date` - First day of the streak
+/// * `end_date` - Last day of the streak
+/// * `total_contributions` - Contributions made on the streak days
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakRecord {
+    /// Number of streak days
+    pub count: u32,
+
+    /// First day of the streak (YYYY-MM-DD)
+    pub start_date: String,
+
+    /// Last day of the streak (YYYY-MM-DD)
+    pub end_date: String,
+
+    /// Contributions made on the streak days
+    pub total_contributions: u32,
+}
+
+/// A break between two streaks.
+///
+/// # Fields
+///
+/// * `start_date` - First day without a streak
+/// * `end_date` - Last day without a streak
+/// * `days` - Length of the break in calendar days
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakGap {
+    /// First day of the break (YYYY-MM-DD)
+    pub start_date: String,
+
+    /// Last day of the break (YYYY-MM-DD)
+    pub end_date: String,
+
+    /// Length of the break in calendar days
+    pub days: u32,
+}
+
+/// Every streak and break in the contribution history.
+///
+/// ```text
+/// ██████░░░████░░░░░███
+/// └────┘   └──┘     └─┘   ◀── streaks
+///       └─┘    └───┘      ◀── gaps (longest_gap = 5 days)
+/// ```
+///
+/// # Fields
+///
+/// * `streaks` - All streaks in chronological order
+/// * `gaps` - Breaks between consecutive streaks in chronological order
+/// * `longest_gap` - The longest break, if there were at least two streaks
+/// * `days_since_last_contribution` - Days since the last contribution, if any
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakHistory {
+    /// All streaks in chronological order
+    pub streaks: Vec<StreakRecord>,
+
+    /// Breaks between consecutive streaks in chronological order
+    pub gaps: Vec<StreakGap>,
+
+    /// The longest break between two streaks
+    pub longest_gap: Option<StreakGap>,
+
+    /// Days since the last contribution (0 = contributed today)
+    pub days_since_last_contribution: Option<u32>,
+}
+
+// ============================================================================
+// Aggregates
+// ============================================================================
+
+/// Total contributions in a calendar month.
+///
+/// # Fields
+///
+/// * `month` - The month in YYYY-MM format
+/// * `total` - Sum of contributions in the month
+/// * `active_days` - Days in the month with at least one contribution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthlyTotal {
+    /// Month (YYYY-MM)
+    pub month: String,
+
+    /// Sum of contributions in the month
+    pub total: u32,
+
+    /// Days with at least one contribution
+    pub active_days: u32,
+}
+
+/// Contributions made on one day of the week.
+///
+/// # Fields
+///
+/// * `weekday` - Day of the week (0 = Sunday … 6 = Saturday)
+/// * `total` - Sum of contributions on this weekday
+/// * `mean` - Average contributions per occurrence of this weekday
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeekdayStats {
+    /// Day of the week (0 = Sunday)
+    pub weekday: u8,
+
+    /// Sum of contributions on this weekday
+    pub total: u32,
+
+    /// Average contributions per occurrence of this weekday
+    pub mean: f32,
+}
+
+/// Total contributions in a Sunday-Saturday week.
+///
+/// # Fields
+///
+/// * `start_date` - First day of the week in the data (YYYY-MM-DD)
+/// * `end_date` - Last day of the week in the data (YYYY-MM-DD)
+/// * `total` - Sum of contributions in the week
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeeklyTotal {
+    /// First day of the week (YYYY-MM-DD)
+    pub start_date: String,
+
+    /// Last day of the week (YYYY-MM-DD)
+    pub end_date: String,
+
+    /// Sum of contributions in the week
+    pub total: u32,
+}
+
+// ============================================================================
+// Trends
+// ============================================================================
+
+/// Average contributions per day over recent windows ending "today".
+///
+/// # Fields
+///
+/// * `last_7_days` - Mean over the last 7 days
+/// * `last_30_days` - Mean over the last 30 days
+/// * `last_90_days` - Mean over the last 90 days
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RollingAverages {
+    /// Mean contributions per day over the last 7 days
+    #[serde(rename = "last7Days")]
+    pub last_7_days: f32,
+
+    /// Mean contributions per day over the last 30 days
+    #[serde(rename = "last30Days")]
+    pub last_30_days: f32,
+
+    /// Mean contributions per day over the last 90 days
+    #[serde(rename = "last90Days")]
+    pub last_90_days: f32,
+}
+
+/// Contributions in a period compared with the period before it.
+///
+/// # Fields
+///
+/// * `current` - Contributions in the most recent period
+/// * `previous` - Contributions in the period before it
+/// * `change_percent` - Relative change, `None` if `previous` is 0
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodChange {
+    /// Contributions in the most recent period
+    pub current: u32,
+
+    /// Contributions in the period before it
+    pub previous: u32,
+
+    /// Change in percent (e.g. 50.0 = 50% more)
+    pub change_percent: Option<f32>,
+}
+
+/// Direction of recent activity (7-day vs. 30-day average).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Trend {
+    /// Noticeably more active than usual
+    Up,
+
+    /// About as active as usual
+    Flat,
+
+    /// Noticeably less active than usual
+    Down,
+}
+
+// ============================================================================
+// Distribution
+// ============================================================================
+
+/// Share of active days (at least one contribution) in recent windows.
+///
+/// Percentages (0-100) of the days with data in each window.
+///
+/// # Fields
+///
+/// * `last_30_days` - Active share of the last 30 days
+/// * `last_90_days` - Active share of the last 90 days
+/// * `last_365_days` - Active share of the last 365 days
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveDayShares {
+    /// Percentage of active days in the last 30 days
+    #[serde(rename = "last30Days")]
+    pub last_30_days: f32,
+
+    /// Percentage of active days in the last 90 days
+    #[serde(rename = "last90Days")]
+    pub last_90_days: f32,
+
+    /// Percentage of active days in the last 365 days
+    #[serde(rename = "last365Days")]
+    pub last_365_days: f32,
+}
+
+// ============================================================================
+// Streak Rules
+// ============================================================================
+
+/// Rules that decide which days a streak has to cover.
+///
+/// Rest weekdays neither extend nor break a streak, so someone who
+/// doesn't code on weekends keeps their streak from Friday to Monday.
+///
+/// ```text
+/// rest_days: [0, 6]   (Sunday, Saturday)
+///
+/// Fri  Sat  Sun  Mon
+///  █    ·    ·    █    ◀── One unbroken 2-day streak
+/// ```
+///
+/// Days below `min_daily` contributions don't count as streak days, so a
+/// single typo fix doesn't keep a streak alive when the threshold is 3.
+///
+/// # Fields
+///
+/// * `rest_days` - Rest weekdays, numbered from Sunday (0) to Saturday (6)
+/// * `min_daily` - Minimum contributions for a day to count towards a streak
+/// * `weekly_target` - Minimum contributions for a week to count towards a weekly streak
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakRules {
+    /// Rest weekdays (0 = Sunday … 6 = Saturday)
+    pub rest_days: Vec<u8>,
+
+    /// Minimum contributions for a streak day (at least 1)
+    pub min_daily: u32,
+
+    /// Minimum contributions for a streak week (at least 1)
+    pub weekly_target: u32,
+}
+
+impl Default for StreakRules {
+    /// Every day counts and a single contribution is enough (per day and per week).
+    fn default() -> Self {
+        Self {
+            rest_days: Vec::new(),
+            min_daily: 1,
+            weekly_target: 1,
+        }
+    }
+}
+
+impl StreakRules {
+    /// Returns whether a day's count is enough to extend a streak.
+    pub fn is_streak_day(&self, count: u32) -> bool {
+        count >= self.min_daily.max(1)
+    }
+
+    /// Returns the same rules with every weekday counting (used for strict streaks).
+    pub fn without_rest_days(&self) -> Self {
+        Self {
+            rest_days: Vec::new(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns whether a date falls on a rest weekday.
+    pub fn is_rest_day(&self, date: NaiveDate) -> bool {
+        let weekday = date.weekday().num_days_from_sunday() as u8;
+        self.rest_days.contains(&weekday)
+    }
+
+    /// Returns the last non-rest date before `date`.
+    ///
+    /// If every weekday is a rest day (which settings validation
+    /// rejects) this falls back to the previous calendar day.
+    pub fn previous_working_day(&self, date: NaiveDate) -> NaiveDate {
+        (1..=7)
+            .map(|offset| date - Duration::days(offset))
+            .find(|day| !self.is_rest_day(*day))
+            .unwrap_or(date - Duration::days(1))
+    }
+
+    /// Returns the first non-rest date after `date`.
+    pub fn next_working_day(&self, date: NaiveDate) -> NaiveDate {
+        (1..=7)
+            .map(|offset| date + Duration::days(offset))
+            .find(|day| !self.is_rest_day(*day))
+            .unwrap_or(date + Duration::days(1))
+    }
+}
+
+// ============================================================================
+// Level Scale
+// ============================================================================
+
+/// How contribution counts map to heatmap levels (0-4).
+///
+/// ```text
+/// Quartiles (GitHub style)          Thresholds [1, 4, 8, 15]
+/// ─────────────────────────         ─────────────────────────
+/// 0            ──▶ level 0          0       ──▶ level 0
+/// ≤ 1st quart. ──▶ level 1          1-3     ──▶ level 1
+/// ≤ median     ──▶ level 2          4-7     ──▶ level 2
+/// ≤ 3rd quart. ──▶ level 3          8-14    ──▶ level 3
+/// above        ──▶ level 4          15+     ──▶ level 4
+/// ```
+///
+/// Quartiles are taken over the non-zero days only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LevelScale {
+    /// Quartiles of the non-zero daily counts
+    #[default]
+    Quartiles,
+
+    /// Minimum counts for levels 1 to 4 (ascending)
+    Thresholds([u32; 4]),
+}
+
+impl LevelScale {
+    /// Builds a scale from stored thresholds.
+    ///
+    /// Anything but four thresholds falls back to quartiles.
+    pub fn from_thresholds(thresholds: &[u32]) -> Self {
+        <[u32; 4]>::try_from(thresholds)
+            .map(LevelScale::Thresholds)
+            .unwrap_or_default()
+    }
+}
+
 // ============================================================================
 // Contribution Statistics
 // ============================================================================
@@ -159,6 +552,15 @@ pub struct Streak {
 /// │ Average/Day      │ Mean contributions per day           │
 /// │ Current Streak   │ Ongoing streak (ends today/yesterday)│
 /// │ Longest Streak   │ Highest streak ever achieved         │
+/// │ Relaxed Streaks  │ Same, with rest weekdays skipped     │
+/// │ Streak Threshold │ Contributions needed per streak day  │
+/// │ Weekly Streaks   │ Consecutive weeks reaching a target  │
+/// │ Monthly Streaks  │ Consecutive months with activity     │
+/// │ Aggregates       │ Totals per month, weekday and week   │
+/// │ Trends           │ Rolling averages, changes, direction │
+/// │ Distribution     │ Median, deviation, days per level    │
+/// │ Consistency      │ Active days share, 0-100 score       │
+/// │ Reported Total   │ Total from GitHub's calendar header  │
 /// └──────────────────┴──────────────────────────────────────┘
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -178,29 +580,175 @@ pub struct ContributionStats {
     
     /// Longest streak ever achieved
     pub longest_streak: Streak,
+
+    /// Current streak with rest days skipped (equals `current_streak`
+    /// when no rest days are configured)
+    pub relaxed_current_streak: Streak,
+
+    /// Longest streak with rest days skipped
+    pub relaxed_longest_streak: Streak,
+
+    /// Rest weekdays used for the relaxed streaks (0 = Sunday)
+    pub rest_days: Vec<u8>,
+
+    /// Minimum contributions a day needed to count towards the streaks above
+    pub streak_threshold: u32,
+
+    /// Ongoing run of consecutive weeks reaching `weekly_target` (count in weeks)
+    pub current_weekly_streak: Streak,
+
+    /// Longest run of consecutive weeks reaching `weekly_target`
+    pub longest_weekly_streak: Streak,
+
+    /// Minimum contributions a week needed to count towards the weekly streaks
+    pub weekly_target: u32,
+
+    /// Ongoing run of consecutive months with activity (count in months)
+    pub current_monthly_streak: Streak,
+
+    /// Longest run of consecutive months with activity
+    pub longest_monthly_streak: Streak,
+
+    /// Contributions per calendar month, in chronological order
+    pub monthly_totals: Vec<MonthlyTotal>,
+
+    /// Total and mean contributions per weekday, Sunday first
+    pub weekday_distribution: Vec<WeekdayStats>,
+
+    /// Contributions per Sunday-Saturday week, in chronological order
+    pub weekly_totals: Vec<WeeklyTotal>,
+
+    /// Week with the most contributions (`None` without any activity)
+    pub best_week: Option<WeeklyTotal>,
+
+    /// Month with the most contributions (`None` without any activity)
+    pub best_month: Option<MonthlyTotal>,
+
+    /// Last 7 days compared with the 7 days before
+    pub week_over_week: PeriodChange,
+
+    /// Last 30 days compared with the 30 days before
+    pub month_over_month: PeriodChange,
+
+    /// Rolling 7/30/90-day averages
+    pub rolling_averages: RollingAverages,
+
+    /// Direction of recent activity
+    pub trend: Trend,
+
+    /// Median contributions per day
+    pub median_per_day: f32,
+
+    /// Standard deviation of the daily contributions
+    pub std_deviation: f32,
+
+    /// Percentage of active days over the last 30/90/365 days
+    pub active_days: ActiveDayShares,
+
+    /// Number of days per heatmap level (index 0 = level 0 … 4 = level 4)
+    pub level_histogram: [u32; 5],
+
+    /// How regularly contributions happen, independent of volume (0-100)
+    pub consistency_score: u8,
+
+    /// Total shown in GitHub's calendar header ("N contributions in the last year"),
+    /// if it could be found in the HTML
+    pub reported_total: Option<u32>,
+
+    /// Whether `total_contributions` disagrees with `reported_total`, meaning
+    /// some daily counts were estimated from their level
+    pub total_is_estimate: bool,
 }
 
 // ============================================================================
 // User Info
 // ============================================================================
 
-/// Basic information about the GitHub user.
+/// Information about the GitHub user.
 ///
-/// This is displayed in the header of the popup window,
-/// showing the user's avatar and username.
+/// This is displayed in the header of the popup window, showing the
+/// user's avatar, name and profile details. Everything except the
+/// username and avatar comes from the public profile and is `None`
+/// when the profile couldn't be fetched.
 ///
 /// # Fields
 ///
 /// * `username` - The GitHub username
 /// * `avatar_url` - URL to the user's avatar image
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// * `avatar_data_uri` - Cached avatar as a `data:` URI, if available
+/// * `display_name` - Profile display name
+/// * `bio` - Profile bio
+/// * `company` - Company field from the profile
+/// * `followers` - Number of followers
+/// * `following` - Number of accounts the user follows
+/// * `created_at` - Account creation timestamp (start of contribution history)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserInfo {
     /// GitHub username
     pub username: String,
     
-    /// URL to avatar image (GitHub's avatar pattern: https://github.com/{username}.png)
+    /// URL to avatar image (canonical avatar URL, or https://github.com/{username}.png)
     pub avatar_url: String,
+    
+    /// Avatar image as a `data:` URI from the local avatar cache (works offline)
+    #[serde(default)]
+    pub avatar_data_uri: Option<String>,
+    
+    /// Display name ("The Octocat")
+    #[serde(default)]
+    pub display_name: Option<String>,
+    
+    /// Profile bio
+    #[serde(default)]
+    pub bio: Option<String>,
+    
+    /// Company field from the profile
+    #[serde(default)]
+    pub company: Option<String>,
+    
+    /// Number of followers
+    #[serde(default)]
+    pub followers: Option<u32>,
+    
+    /// Number of accounts the user follows
+    #[serde(default)]
+    pub following: Option<u32>,
+    
+    /// Account creation timestamp (ISO 8601)
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+// ============================================================================
+// Username Validation
+// ============================================================================
+
+/// Result of validating a GitHub username.
+///
+/// Returned to the settings form so it can show why a username
+/// was rejected before it is saved.
+///
+/// # Fields
+///
+/// * `username` - The normalized username (trimmed, lowercased)
+/// * `valid` - Whether the username follows GitHub's login rules
+/// * `exists` - Whether the account exists, if it was checked
+/// * `error` - Human-readable problem with the username, if any
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsernameValidation {
+    /// Normalized username
+    pub username: String,
+    
+    /// Whether the username follows GitHub's login rules
+    pub valid: bool,
+    
+    /// Whether the account exists (`None` if not checked or the check failed)
+    pub exists: Option<bool>,
+    
+    /// Description of the problem, if any
+    pub error: Option<String>,
 }
 
 // ============================================================================
@@ -233,6 +781,30 @@ pub struct ContributionWeek {
     pub days: Vec<ContributionDay>,
 }
 
+// ============================================================================
+// Private Contributions
+// ============================================================================
+
+/// Whether the calendar counts include private (anonymous) contributions.
+///
+/// Users who enable "Private contributions" on their profile get their
+/// private work added to the calendar as anonymous counts. This explains
+/// why a calendar can be busier than the user's public repositories.
+///
+/// # Fields
+///
+/// * `included` - Whether private contribution markers were found
+/// * `count` - Number of private contributions, if GitHub states it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrivateContributions {
+    /// Whether the calendar includes private contributions
+    pub included: bool,
+    
+    /// Number of private contributions ("N contributions in private repositories")
+    pub count: Option<u32>,
+}
+
 // ============================================================================
 // Contribution Data
 // ============================================================================
@@ -251,6 +823,7 @@ pub struct ContributionWeek {
 ///                              ├── user (header display)
 ///                              ├── weeks (heatmap grid)
 ///                              ├── stats (statistics cards)
+///                              ├── private_contributions (notice)
 ///                              └── last_updated (footer)
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -265,10 +838,588 @@ pub struct ContributionData {
     /// Calculated statistics
     pub stats: ContributionStats,
     
+    /// Private contribution markers detected in the calendar
+    pub private_contributions: PrivateContributions,
+    
     /// ISO 8601 timestamp of when data was fetched
     pub last_updated: String,
 }
 
+impl ContributionData {
+    /// Returns all contribution days in chronological order.
+    ///
+    /// Flattens the week structure back into the day series
+    /// used for statistics calculation.
+    pub fn days(&self) -> Vec<ContributionDay> {
+        self.weeks
+            .iter()
+            .flat_map(|week| week.days.iter().cloned())
+            .collect()
+    }
+}
+
+// ============================================================================
+// Range Statistics
+// ============================================================================
+
+/// The heatmap views, used as shortcuts for a stats date range.
+///
+/// ```text
+/// Preset   Heatmap button   Range
+/// ──────────────────────────────────────────
+/// "30d"    30 Days          ~last 30 days
+/// "12w"    12 Weeks         ~last 84 days
+/// "year"   Year             all cached days
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RangePreset {
+    /// The "30 Days" heatmap view
+    #[serde(rename = "30d")]
+    Last30Days,
+
+    /// The "12 Weeks" heatmap view
+    #[serde(rename = "12w")]
+    Last12Weeks,
+
+    /// The full-year heatmap view
+    #[serde(rename = "year")]
+    Year,
+}
+
+/// Statistics and heatmap weeks for a date range.
+///
+/// # Fields
+///
+/// * `start_date` - First date of the range
+/// * `end_date` - Last date of the range
+/// * `stats` - Statistics calculated from the days in the range only
+/// * `weeks` - The days in the range grouped into weeks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RangeStats {
+    /// First date of the range (YYYY-MM-DD)
+    pub start_date: String,
+
+    /// Last date of the range (YYYY-MM-DD)
+    pub end_date: String,
+
+    /// Statistics for the range
+    pub stats: ContributionStats,
+
+    /// Weeks of the range for heatmap rendering
+    pub weeks: Vec<ContributionWeek>,
+}
+
+// ============================================================================
+// Export
+// ============================================================================
+
+/// File format for `export_contributions`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// Sectioned CSV (metadata, days, stats, streaks)
+    Csv,
+
+    /// Pretty-printed JSON
+    Json,
+}
+
+/// Everything written by `export_contributions`.
+///
+/// The layout is versioned by `schema_version`. It only uses the export
+/// types below, never the internal statistics types, so changes to
+/// `ContributionStats` don't change the export. Fields are only ever
+/// added within a version, never renamed or removed.
+///
+/// # Fields
+///
+/// * `schema_version` - Export schema version
+/// * `username` - The tracked GitHub login
+/// * `exported_at` - ISO 8601 timestamp of the export
+/// * `start_date` / `end_date` - The exported date range
+/// * `days` - Every day in the range
+/// * `stats` - Statistics for the range
+/// * `streaks` - Every streak in the range, chronologically
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionExport {
+    /// Export schema version
+    pub schema_version: u32,
+
+    /// The tracked GitHub login
+    pub username: String,
+
+    /// When the export was written (ISO 8601)
+    pub exported_at: String,
+
+    /// First exported date (YYYY-MM-DD)
+    pub start_date: String,
+
+    /// Last exported date (YYYY-MM-DD)
+    pub end_date: String,
+
+    /// Every day in the range
+    pub days: Vec<ExportDay>,
+
+    /// Statistics calculated from the exported days
+    pub stats: ExportStats,
+
+    /// Every streak in the range
+    pub streaks: Vec<ExportStreak>,
+}
+
+/// A day in the export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportDay {
+    /// Date in YYYY-MM-DD format
+    pub date: String,
+
+    /// Number of contributions on this day
+    pub count: u32,
+
+    /// Heatmap level (0-4)
+    pub level: u8,
+}
+
+/// Statistics in the export.
+///
+/// Streak fields are lengths only (days, weeks or months); the streak
+/// dates are in `ContributionExport::streaks`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportStats {
+    /// Total number of contributions in the range
+    pub total_contributions: u32,
+
+    /// Date of the best day (YYYY-MM-DD)
+    pub best_day_date: String,
+
+    /// Contributions on the best day
+    pub best_day_count: u32,
+
+    /// Average contributions per day
+    pub average_per_day: f32,
+
+    /// Median contributions per day
+    pub median_per_day: f32,
+
+    /// Standard deviation of the daily contributions
+    pub std_deviation: f32,
+
+    /// Current streak in days
+    pub current_streak: u32,
+
+    /// Longest streak in days
+    pub longest_streak: u32,
+
+    /// Current streak in days, rest days skipped
+    pub relaxed_current_streak: u32,
+
+    /// Longest streak in days, rest days skipped
+    pub relaxed_longest_streak: u32,
+
+    /// Current weekly streak in weeks
+    pub current_weekly_streak: u32,
+
+    /// Longest weekly streak in weeks
+    pub longest_weekly_streak: u32,
+
+    /// Current monthly streak in months
+    pub current_monthly_streak: u32,
+
+    /// Longest monthly streak in months
+    pub longest_monthly_streak: u32,
+
+    /// How regularly contributions happen (0-100)
+    pub consistency_score: u8,
+
+    /// Contributions per calendar month, chronologically
+    pub monthly_totals: Vec<ExportMonth>,
+
+    /// Contributions per weekday, Sunday first
+    pub weekday_totals: Vec<ExportWeekday>,
+}
+
+/// A calendar month in the export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportMonth {
+    /// Month in YYYY-MM format
+    pub month: String,
+
+    /// Contributions in the month
+    pub total: u32,
+
+    /// Days with at least one contribution
+    pub active_days: u32,
+}
+
+/// A weekday in the export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportWeekday {
+    /// Weekday (0 = Sunday … 6 = Saturday)
+    pub weekday: u8,
+
+    /// Contributions on this weekday
+    pub total: u32,
+
+    /// Mean contributions per occurrence of this weekday
+    pub mean: f32,
+}
+
+/// A streak in the export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportStreak {
+    /// First day of the streak (YYYY-MM-DD)
+    pub start_date: String,
+
+    /// Last day of the streak (YYYY-MM-DD)
+    pub end_date: String,
+
+    /// Length in days
+    pub count: u32,
+
+    /// Contributions during the streak
+    pub total_contributions: u32,
+}
+
+// ============================================================================
+// Import
+// ============================================================================
+
+/// Input format for `import_contributions`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ImportFormat {
+    /// `date,count` rows, or the days section of an exported CSV
+    Csv,
+
+    /// One date per commit, from `git log --format=%ad --date=short`
+    GitLog,
+}
+
+/// How imported counts are combined with counts already known for a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MergePolicy {
+    /// Keep the higher of the two counts (safe to repeat)
+    #[default]
+    Max,
+
+    /// Add the imported count (e.g. commits GitHub doesn't know about)
+    Sum,
+
+    /// Use the imported count
+    Replace,
+}
+
+/// The days of one `import_contributions` call, merged into every fetch.
+///
+/// Imports are stored per user as separate batches and replayed in
+/// import order, each with its own policy, so a later import never
+/// changes how an earlier one is merged.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportBatch {
+    /// How this batch is combined with the days before it
+    pub policy: MergePolicy,
+
+    /// The imported days, chronologically
+    pub days: Vec<ContributionDay>,
+}
+
+/// Summary of an `import_contributions` call.
+///
+/// # Fields
+///
+/// * `days` - Number of distinct days imported
+/// * `contributions` - Sum of the imported counts
+/// * `skipped_days` - Days outside GitHub's calendar window, not imported
+/// * `start_date` / `end_date` - Range of the imported days
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportSummary {
+    /// Number of distinct days imported
+    pub days: u32,
+
+    /// Sum of the imported counts
+    pub contributions: u32,
+
+    /// Days before GitHub's calendar window or after today, not imported
+    pub skipped_days: u32,
+
+    /// First imported date (YYYY-MM-DD)
+    pub start_date: String,
+
+    /// Last imported date (YYYY-MM-DD)
+    pub end_date: String,
+}
+
+// ============================================================================
+// Goals
+// ============================================================================
+
+/// The period a goal's target applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GoalPeriod {
+    /// Target per calendar day
+    Day,
+
+    /// Target per Sunday-Saturday week
+    Week,
+
+    /// Target per calendar month
+    Month,
+}
+
+/// A personal contribution target, e.g. "150 per month".
+///
+/// # Fields
+///
+/// * `id` - Unique identifier (chosen by the frontend)
+/// * `name` - Display name
+/// * `period` - Period the target applies to
+/// * `target` - Contributions needed per period
+/// * `weekdays` - Weekdays the goal applies to (empty = every day)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Goal {
+    /// Unique identifier
+    pub id: String,
+
+    /// Display name ("Daily five")
+    pub name: String,
+
+    /// Period the target applies to
+    pub period: GoalPeriod,
+
+    /// Contributions needed per period
+    pub target: u32,
+
+    /// Weekdays the goal applies to (0 = Sunday … 6 = Saturday, empty = all)
+    #[serde(default)]
+    pub weekdays: Vec<u8>,
+}
+
+/// Progress of a goal in the current period.
+///
+/// # Fields
+///
+/// * `goal` - The goal definition
+/// * `period_start` / `period_end` - Bounds of the current period
+/// * `current` - Contributions so far in the current period
+/// * `met` - Whether the target is reached
+/// * `percent` - Progress towards the target (capped at 100)
+/// * `current_hit_streak` - Consecutive periods the goal was met
+/// * `longest_hit_streak` - Most consecutive periods the goal was met
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalProgress {
+    /// The goal definition
+    pub goal: Goal,
+
+    /// First day of the current period (YYYY-MM-DD)
+    pub period_start: String,
+
+    /// Last day of the current period (YYYY-MM-DD)
+    pub period_end: String,
+
+    /// Contributions so far in the current period
+    pub current: u32,
+
+    /// Whether the target is reached for the current period
+    pub met: bool,
+
+    /// Progress towards the target in percent (0-100)
+    pub percent: f32,
+
+    /// Consecutive periods the goal was met (the current period gets grace)
+    pub current_hit_streak: u32,
+
+    /// Most consecutive periods the goal was met
+    pub longest_hit_streak: u32,
+}
+
+// ============================================================================
+// Achievements
+// ============================================================================
+
+/// The kind of milestone an achievement stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AchievementKind {
+    /// Longest streak reached a number of days
+    Streak,
+
+    /// Total contributions reached a milestone
+    Total,
+
+    /// A new best day ever
+    BestDay,
+}
+
+/// An unlocked achievement, persisted with its unlock date.
+///
+/// # Fields
+///
+/// * `id` - Stable identifier (e.g. "streak-30", "total-1000", "best-day-42")
+/// * `kind` - The kind of milestone
+/// * `title` - Short title for the badge
+/// * `description` - What was achieved
+/// * `value` - The milestone value (days, contributions)
+/// * `unlocked_at` - ISO 8601 timestamp of the unlock
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Achievement {
+    /// Stable identifier
+    pub id: String,
+
+    /// The kind of milestone
+    pub kind: AchievementKind,
+
+    /// Short title ("30-day streak")
+    pub title: String,
+
+    /// What was achieved ("Contributed 30 days in a row")
+    pub description: String,
+
+    /// The milestone value (days or contributions)
+    pub value: u32,
+
+    /// When the achievement was unlocked (ISO 8601)
+    pub unlocked_at: String,
+}
+
+/// Achievements of one tracked user, persisted per username.
+///
+/// The best-day record is seeded silently from the first fetch, so
+/// the existing history doesn't count as a new record.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserAchievements {
+    /// Unlocked achievements in the order they were unlocked
+    pub unlocked: Vec<Achievement>,
+
+    /// Highest best day seen so far (`None` before the first fetch)
+    pub best_day_record: Option<u32>,
+}
+
+// ============================================================================
+// Projections
+// ============================================================================
+
+/// Projections based on the current streak and the recent daily rate.
+///
+/// # Fields
+///
+/// * `current_streak` - Current streak in days (relaxed rules)
+/// * `next_streak_milestone` - Next streak milestone (7, 30, 100, 365 days)
+/// * `next_milestone_date` - Date the milestone is reached if the streak continues
+/// * `daily_rate` - Recent daily rate (30-day rolling average)
+/// * `year_to_date` - Contributions so far this calendar year
+/// * `days_remaining` - Days left in the year after today
+/// * `projected_year_end_total` - Year-to-date plus the daily rate for the remaining days
+/// * `last_year_total` - Last calendar year's total, if it could be fetched
+/// * `needed_per_day` - Contributions per remaining day needed to beat last year
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Projections {
+    /// Current streak in days
+    pub current_streak: u32,
+
+    /// Next streak milestone in days (None once every milestone is passed)
+    pub next_streak_milestone: Option<u32>,
+
+    /// Date the next milestone is reached if the streak continues (YYYY-MM-DD)
+    pub next_milestone_date: Option<String>,
+
+    /// Recent daily rate (30-day rolling average)
+    pub daily_rate: f32,
+
+    /// Contributions so far this calendar year
+    pub year_to_date: u32,
+
+    /// Days left in the year after today
+    pub days_remaining: u32,
+
+    /// Projected total on December 31st at the recent daily rate
+    pub projected_year_end_total: u32,
+
+    /// Last calendar year's total (None if it couldn't be fetched)
+    pub last_year_total: Option<u32>,
+
+    /// Contributions per remaining day needed to beat last year's total
+    /// (0 once beaten, None if unknown or no days remain)
+    pub needed_per_day: Option<f32>,
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+/// Color theme for rendered images (SVG heatmap, PNG card, badge).
+///
+/// Unlike `Settings.theme` there is no "system" option: an image is
+/// always rendered in one concrete theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderTheme {
+    /// GitHub light palette
+    #[default]
+    Light,
+
+    /// GitHub dark palette
+    Dark,
+}
+
+impl RenderTheme {
+    /// Resolves the `Settings.theme` value to a concrete theme.
+    ///
+    /// # Arguments
+    ///
+    /// * `setting` - "system", "light" or "dark"
+    /// * `system_dark` - Whether the OS is currently in dark mode
+    pub fn from_setting(setting: &str, system_dark: bool) -> Self {
+        match setting {
+            "dark" => RenderTheme::Dark,
+            "light" => RenderTheme::Light,
+            _ if system_dark => RenderTheme::Dark,
+            _ => RenderTheme::Light,
+        }
+    }
+}
+
+/// Layout of the README streak badge.
+///
+/// ```text
+/// Flat / FlatSquare:  ┌────────┬────────────────────────────────────┐
+///                     │ streak │ 12 days · longest 45 · 1,234 total │
+///                     └────────┴────────────────────────────────────┘
+///
+/// Card:               ┌────────────────────────────────────────────┐
+///                     │   1,234          12               45       │
+///                     │   Total    Current streak   Longest streak │
+///                     └────────────────────────────────────────────┘
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BadgeStyle {
+    /// Shields-style badge with rounded corners
+    #[default]
+    Flat,
+
+    /// Shields-style badge with square corners
+    FlatSquare,
+
+    /// Three-column card with large numbers
+    Card,
+}
+
 // ============================================================================
 // Settings
 // ============================================================================
@@ -307,6 +1458,98 @@ pub struct Settings {
     
     /// UI theme: "system", "light", or "dark"
     pub theme: String,
+    
+    /// IANA timezone per tracked username (e.g. "octocat" → "Europe/Berlin").
+    /// Users without an entry follow the system timezone.
+    #[serde(default)]
+    pub timezones: HashMap<String, String>,
+
+    /// Rest weekdays that neither extend nor break a streak (0 = Sunday … 6 = Saturday)
+    #[serde(default)]
+    pub rest_days: Vec<u8>,
+
+    /// Minimum contributions for a day to count towards a streak (default: 1)
+    #[serde(default = "default_streak_threshold")]
+    pub streak_threshold: u32,
+
+    /// Minimum contributions for a week to count towards a weekly streak (default: 1)
+    #[serde(default = "default_weekly_target")]
+    pub weekly_target: u32,
+
+    /// Minimum counts for heatmap levels 1 to 4; empty uses GitHub-style quartiles
+    #[serde(default)]
+    pub level_thresholds: Vec<u32>,
+
+    /// File the streak badge is written to after every refresh; empty disables it
+    #[serde(default)]
+    pub badge_path: String,
+
+    /// Layout of the streak badge
+    #[serde(default)]
+    pub badge_style: BadgeStyle,
+
+    /// Color theme of the streak badge
+    #[serde(default)]
+    pub badge_theme: RenderTheme,
+
+    /// Whether the local HTTP API on 127.0.0.1 is running
+    #[serde(default)]
+    pub api_enabled: bool,
+
+    /// Port of the local HTTP API (default: 7878)
+    #[serde(default = "default_api_port")]
+    pub api_port: u16,
+
+    /// Bearer token required by the local HTTP API; generated when the API is first enabled
+    #[serde(default)]
+    pub api_token: String,
+}
+
+/// Default streak threshold: any contribution counts.
+fn default_streak_threshold() -> u32 {
+    1
+}
+
+/// Default weekly target: any contribution in a week counts.
+fn default_weekly_target() -> u32 {
+    1
+}
+
+/// Default port of the local HTTP API.
+pub fn default_api_port() -> u16 {
+    7878
+}
+
+impl Settings {
+    /// Returns the timezone setting for a tracked user.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - A normalized GitHub login
+    ///
+    /// # Returns
+    ///
+    /// The IANA timezone name, or `"system"` if none is set
+    pub fn timezone_for(&self, username: &str) -> &str {
+        self.timezones
+            .get(username)
+            .map(String::as_str)
+            .unwrap_or(crate::timezone::SYSTEM_TIMEZONE)
+    }
+
+    /// Returns the streak rules configured in these settings.
+    pub fn streak_rules(&self) -> StreakRules {
+        StreakRules {
+            rest_days: self.rest_days.clone(),
+            min_daily: self.streak_threshold,
+            weekly_target: self.weekly_target,
+        }
+    }
+
+    /// Returns the level scale configured in these settings.
+    pub fn level_scale(&self) -> LevelScale {
+        LevelScale::from_thresholds(&self.level_thresholds)
+    }
 }
 
 impl Default for Settings {
@@ -318,6 +1561,17 @@ impl Default for Settings {
     /// - `icon_style`: "green"
     /// - `launch_at_login`: false
     /// - `theme`: "system" (follows OS preference)
+    /// - `timezones`: empty (every user follows the system timezone)
+    /// - `rest_days`: empty (every day counts towards streaks)
+    /// - `streak_threshold`: 1 (any contribution counts)
+    /// - `weekly_target`: 1 (any week with a contribution counts)
+    /// - `level_thresholds`: empty (GitHub-style quartiles)
+    /// - `badge_path`: empty (no badge is written)
+    /// - `badge_style`: flat
+    /// - `badge_theme`: light
+    /// - `api_enabled`: false (no server is started)
+    /// - `api_port`: 7878
+    /// - `api_token`: empty (generated when the API is enabled)
     fn default() -> Self {
         Self {
             username: String::new(),
@@ -325,6 +1579,17 @@ impl Default for Settings {
             icon_style: "green".to_string(),
             launch_at_login: false,
             theme: "system".to_string(),
+            timezones: HashMap::new(),
+            rest_days: Vec::new(),
+            streak_threshold: default_streak_threshold(),
+            weekly_target: default_weekly_target(),
+            level_thresholds: Vec::new(),
+            badge_path: String::new(),
+            badge_style: BadgeStyle::default(),
+            badge_theme: RenderTheme::default(),
+            api_enabled: false,
+            api_port: default_api_port(),
+            api_token: String::new(),
         }
     }
 }
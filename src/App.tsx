@@ -137,11 +137,12 @@ import { useState, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { useContributions } from "./hooks/useContributions";
 import { useSettings } from "./hooks/useSettings";
-import { Heatmap } from "./components/heatmap/Heatmap";
+import { Heatmap, type TimeRange } from "./components/heatmap/Heatmap";
 import { StatCard } from "./components/stats/StatCard";
 import { UserHeader } from "./components/user/UserHeader";
-import { formatDate, formatStreak, formatRelativeTime } from "./lib/utils";
-import type { Settings } from "./lib/types";
+import { formatDate, formatStreak, formatRelativeTime, streakLabel } from "./lib/utils";
+import { getRangeStats, validateUsername } from "./lib/api";
+import type { ContributionStats, RangePreset, Settings } from "./lib/types";
 import "./App.css";
 
 // ============================================================================
@@ -303,6 +304,10 @@ function App() {
   // UI state
   const [showSettings, setShowSettings] = useState(false);
   const [isSaving, setIsSaving] = useState(false);
+  const [usernameError, setUsernameError] = useState<string | null>(null);
+  const [timezoneInput, setTimezoneInput] = useState("");
+  const [timezoneError, setTimezoneError] = useState<string | null>(null);
+  const [thresholdInput, setThresholdInput] = useState("1");
 
   // Theme and autostart state (local copies for immediate UI updates)
   const [currentTheme, setCurrentTheme] = useState<Settings["theme"]>("system");
@@ -334,12 +339,39 @@ function App() {
     if (settings?.username) {
       setUsername(settings.username);
       setInputValue(settings.username);
+      setTimezoneInput(settings.timezones[settings.username] ?? "");
+    }
+    if (settings) {
+      setThresholdInput(String(settings.streakThreshold));
     }
   }, [settings]);
 
   // Contribution data from GitHub (fetched via useContributions hook)
   const { data, isLoading, isRefreshing, error, refresh } = useContributions(username);
 
+  // Heatmap view and the stats for just that range (null = full data)
+  const [timeRange, setTimeRange] = useState<TimeRange>("months");
+  const [rangeStats, setRangeStats] = useState<ContributionStats | null>(null);
+
+  /**
+   * Recalculate the stat cards for the visible heatmap range.
+   * Re-runs when new data arrives so the cards never show stale numbers.
+   */
+  useEffect(() => {
+    if (!data || timeRange === "months") {
+      setRangeStats(null);
+      return;
+    }
+
+    const preset: RangePreset = timeRange === "days" ? "30d" : "12w";
+    getRangeStats({ preset })
+      .then((range) => setRangeStats(range.stats))
+      .catch((err) => {
+        console.error("Failed to load range stats:", err);
+        setRangeStats(null);
+      });
+  }, [data, timeRange]);
+
   /**
    * Listen for refresh events from the system tray.
    * When user clicks "Refresh" in tray menu, triggers data refresh.
@@ -367,19 +399,27 @@ function App() {
 
   /**
    * Handles username form submission.
-   * Saves username to settings and triggers data fetch.
+   * Validates the username, then saves it to settings and triggers data fetch.
    */
   const handleUsernameSubmit = async (e: React.FormEvent) => {
     e.preventDefault();
     if (!inputValue.trim()) return;
 
     setIsSaving(true);
+    setUsernameError(null);
     try {
+      const validation = await validateUsername(inputValue, true);
+      if (!validation.valid || validation.exists === false) {
+        setUsernameError(validation.error);
+        return;
+      }
+
       await saveSettings({
         ...settings!,
-        username: inputValue.trim(),
+        username: validation.username,
       });
-      setUsername(inputValue.trim());
+      setUsername(validation.username);
+      setInputValue(validation.username);
       setShowSettings(false);
     } catch (err) {
       console.error("Failed to save settings:", err);
@@ -408,6 +448,69 @@ function App() {
     }
   };
 
+  /**
+   * Handles timezone form submission.
+   * Stores the timezone for the current user (empty = system timezone).
+   * Streaks are recalculated by the backend, then data is refreshed.
+   */
+  const handleTimezoneSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!settings || !username) return;
+
+    const timezones = { ...settings.timezones };
+    if (timezoneInput.trim()) {
+      timezones[username] = timezoneInput.trim();
+    } else {
+      delete timezones[username];
+    }
+
+    setTimezoneError(null);
+    try {
+      await saveSettings({ ...settings, timezones });
+      refresh();
+    } catch (err) {
+      setTimezoneError(String(err));
+    }
+  };
+
+  /**
+   * Toggles a weekday as a rest day.
+   * Rest days neither extend nor break the relaxed streaks.
+   */
+  const handleRestDayToggle = async (weekday: number) => {
+    if (!settings) return;
+
+    const restDays = settings.restDays.includes(weekday)
+      ? settings.restDays.filter((day) => day !== weekday)
+      : [...settings.restDays, weekday].sort();
+
+    try {
+      await saveSettings({ ...settings, restDays });
+      refresh();
+    } catch (err) {
+      console.error("Failed to save rest days:", err);
+    }
+  };
+
+  /**
+   * Handles streak threshold form submission.
+   * Days below the threshold don't count towards streaks.
+   */
+  const handleThresholdSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!settings) return;
+
+    const streakThreshold = parseInt(thresholdInput, 10);
+    if (!Number.isInteger(streakThreshold) || streakThreshold < 1) return;
+
+    try {
+      await saveSettings({ ...settings, streakThreshold });
+      refresh();
+    } catch (err) {
+      console.error("Failed to save streak threshold:", err);
+    }
+  };
+
   /**
    * Handles launch at login toggle.
    * Calls Tauri backend to enable/disable autostart, then saves to settings.
@@ -571,6 +674,11 @@ function App() {
                 {isSaving ? "..." : "Save"}
               </button>
             </div>
+            {usernameError && (
+              <p className="text-xs mt-2" style={{ color: "var(--accent-red)" }}>
+                {usernameError}
+              </p>
+            )}
           </form>
 
           {/* Theme section */}
@@ -604,6 +712,116 @@ function App() {
             </div>
           </div>
 
+          {/* Timezone section */}
+          {!isWelcome && (
+            <form onSubmit={handleTimezoneSubmit} className="mb-6">
+              <label
+                className="block text-xs font-medium mb-2 uppercase tracking-wide"
+                style={{ color: "var(--text-tertiary)" }}
+              >
+                Timezone
+              </label>
+              <div className="flex gap-2">
+                <input
+                  type="text"
+                  value={timezoneInput}
+                  onChange={(e) => setTimezoneInput(e.target.value)}
+                  placeholder="System (e.g. Europe/Berlin)"
+                  className="flex-1 px-3 py-2 rounded-md text-sm focus:outline-none"
+                  style={{
+                    background: "var(--bg-secondary)",
+                    border: "1px solid var(--border-default)",
+                    color: "var(--text-primary)",
+                    fontFamily: "monospace"
+                  }}
+                />
+                <button
+                  type="submit"
+                  className="px-4 py-2 rounded-md font-medium text-sm"
+                  style={{
+                    background: "var(--bg-tertiary)",
+                    color: "var(--text-primary)"
+                  }}
+                >
+                  Set
+                </button>
+              </div>
+              {timezoneError && (
+                <p className="text-xs mt-2" style={{ color: "var(--accent-red)" }}>
+                  {timezoneError}
+                </p>
+              )}
+            </form>
+          )}
+
+          {/* Rest days section */}
+          {!isWelcome && settings && (
+            <div className="mb-6">
+              <label
+                className="block text-xs font-medium mb-2 uppercase tracking-wide"
+                style={{ color: "var(--text-tertiary)" }}
+              >
+                Rest Days
+              </label>
+              <div className="grid grid-cols-7 gap-1">
+                {["S", "M", "T", "W", "T", "F", "S"].map((label, weekday) => {
+                  const isRest = settings.restDays.includes(weekday);
+                  return (
+                    <button
+                      key={weekday}
+                      onClick={() => handleRestDayToggle(weekday)}
+                      className="py-2 rounded-md text-xs transition-colors"
+                      style={{
+                        background: isRest ? "var(--bg-tertiary)" : "var(--bg-secondary)",
+                        border: `1px solid ${isRest ? "var(--accent-green)" : "var(--border-default)"}`,
+                        color: isRest ? "var(--text-primary)" : "var(--text-secondary)",
+                      }}
+                    >
+                      {label}
+                    </button>
+                  );
+                })}
+              </div>
+            </div>
+          )}
+
+          {/* Streak threshold section */}
+          {!isWelcome && (
+            <form onSubmit={handleThresholdSubmit} className="mb-6">
+              <label
+                className="block text-xs font-medium mb-2 uppercase tracking-wide"
+                style={{ color: "var(--text-tertiary)" }}
+              >
+                Contributions per Streak Day
+              </label>
+              <div className="flex gap-2">
+                <input
+                  type="number"
+                  min={1}
+                  value={thresholdInput}
+                  onChange={(e) => setThresholdInput(e.target.value)}
+                  className="flex-1 px-3 py-2 rounded-md text-sm focus:outline-none"
+                  style={{
+                    background: "var(--bg-secondary)",
+                    border: "1px solid var(--border-default)",
+                    color: "var(--text-primary)",
+                    fontFamily: "monospace"
+                  }}
+                />
+                <button
+                  type="submit"
+                  className="px-4 py-2 rounded-md font-medium text-sm"
+                  style={{
+                    background: "var(--bg-tertiary)",
+                    color: "var(--text-primary)"
+                  }}
+                >
+                  Set
+                </button>
+              </div>
+            </form>
+          )}
+
           {/* Launch at Login section */}
           <div>
             <label
@@ -655,6 +873,9 @@ function App() {
 
   // View 5: Main view - Contribution heatmap and stats
   // Shows when username is set, data is loaded, and not in settings
+  // Stat cards follow the heatmap range the user is viewing
+  const stats = rangeStats ?? data.stats;
+
   return (
     <div className="app-container">
       {/* Header */}
@@ -677,45 +898,50 @@ function App() {
       <div className="flex-1 overflow-y-auto overflow-x-hidden p-3">
         {/* Heatmap */}
         <div className="mb-3">
-          <Heatmap weeks={data.weeks} />
+          <Heatmap weeks={data.weeks} onTimeRangeChange={setTimeRange} />
         </div>
 
         {/* Stats grid */}
         <div className="grid grid-cols-2 gap-2">
           <StatCard
             label="Total"
-            value={data.stats.totalContributions}
+            value={stats.totalContributions}
+            sublabel={
+              stats.totalIsEstimate && stats.reportedTotal !== null
+                ? `Estimated · GitHub: ${stats.reportedTotal}`
+                : undefined
+            }
             icon={<ChartIcon />}
           />
           <StatCard
             label="Best Day"
-            value={data.stats.bestDay.count}
-            sublabel={formatDate(data.stats.bestDay.date)}
+            value={stats.bestDay.count}
+            sublabel={formatDate(stats.bestDay.date)}
             icon={<StarIcon />}
           />
           <StatCard
-            label="Longest"
-            value={formatStreak(data.stats.longestStreak)}
+            label={streakLabel("Longest", stats.streakThreshold)}
+            value={formatStreak(stats.relaxedLongestStreak)}
             sublabel={
-              data.stats.longestStreak.count > 0
-                ? `${formatDate(data.stats.longestStreak.startDate)} - ${formatDate(data.stats.longestStreak.endDate)}`
+              stats.relaxedLongestStreak.count > 0
+                ? `${formatDate(stats.relaxedLongestStreak.startDate)} - ${formatDate(stats.relaxedLongestStreak.endDate)}`
                 : undefined
             }
             icon={<FireIcon />}
             variant="highlight"
           />
           <StatCard
-            label="Current"
-            value={formatStreak(data.stats.currentStreak)}
+            label={streakLabel("Current", stats.streakThreshold)}
+            value={formatStreak(stats.relaxedCurrentStreak)}
             sublabel={
-              data.stats.currentStreak.count > 0
-                ? data.stats.currentStreak.startDate === data.stats.currentStreak.endDate
+              stats.relaxedCurrentStreak.count > 0
+                ? stats.relaxedCurrentStreak.startDate === stats.relaxedCurrentStreak.endDate
                   ? "Today"
-                  : `${formatDate(data.stats.currentStreak.startDate)} - ${formatDate(data.stats.currentStreak.endDate)}`
+                  : `${formatDate(stats.relaxedCurrentStreak.startDate)} - ${formatDate(stats.relaxedCurrentStreak.endDate)}`
                 : undefined
             }
             icon={<CalendarIcon />}
-            variant={data.stats.currentStreak.count > 0 ? "highlight" : "default"}
+            variant={stats.relaxedCurrentStreak.count > 0 ? "highlight" : "default"}
           />
         </div>
       </div>
@@ -74,6 +74,9 @@ import { HeatmapTooltip } from "./HeatmapTooltip";
 interface HeatmapProps {
   /** Array of contribution weeks containing daily data */
   weeks: ContributionWeek[];
+
+  /** Called when the user switches the time range (e.g. to update stat cards) */
+  onTimeRangeChange?: (range: TimeRange) => void;
 }
 
 /**
@@ -84,7 +87,7 @@ interface HeatmapProps {
  * - weeks: Last 12 weeks (~3 months)
  * - months: Full year of data
  */
-type TimeRange = "days" | "weeks" | "months";
+export type TimeRange = "days" | "weeks" | "months";
 
 /**
  * Maps contribution level (0-4) to CSS class name.
@@ -214,7 +217,7 @@ function filterWeeksByRange(weeks: ContributionWeek[], range: TimeRange): Contri
  * <Heatmap weeks={contributionData.weeks} />
  * ```
  */
-export function Heatmap({ weeks }: HeatmapProps) {
+export function Heatmap({ weeks, onTimeRangeChange }: HeatmapProps) {
   const [tooltipData, setTooltipData] = useState<{
     day: ContributionDay;
     x: number;
@@ -279,7 +282,10 @@ export function Heatmap({ weeks }: HeatmapProps) {
           {(["days", "weeks", "months"] as TimeRange[]).map((range) => (
             <button
               key={range}
-              onClick={() => setTimeRange(range)}
+              onClick={() => {
+                setTimeRange(range);
+                onTimeRangeChange?.(range);
+              }}
               className="px-2 py-1 text-xs capitalize transition-colors"
               style={{
                 background: timeRange === range ? "var(--bg-tertiary)" : "transparent",
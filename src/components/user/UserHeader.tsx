@@ -92,7 +92,7 @@ export function UserHeader({ user }: UserHeaderProps) {
     <div className="flex items-center gap-3">
       {/* GitHub avatar image */}
       <img
-        src={user.avatarUrl}
+        src={user.avatarDataUri ?? user.avatarUrl}
         alt={`${user.username}'s avatar`}
         className="w-10 h-10 rounded-full"
         style={{ border: "1px solid var(--border-muted)" }}
@@ -105,7 +105,7 @@ export function UserHeader({ user }: UserHeaderProps) {
           className="text-sm font-medium"
           style={{ color: "var(--text-primary)" }}
         >
-          {user.username}
+          {user.displayName ?? user.username}
         </span>
 
         {/* GitHub profile link - opens in external browser */}
@@ -103,6 +103,23 @@ const DEFAULT_SETTINGS: Settings = {
 
   /** Follow system dark/light mode */
   theme: "system",
+
+  /** Every user follows the system timezone */
+  timezones: {},
+  restDays: [],
+  streakThreshold: 1,
+  weeklyTarget: 1,
+  levelThresholds: [],
+
+  /** No badge is written until a path is set */
+  badgePath: "",
+  badgeStyle: "flat",
+  badgeTheme: "light",
+
+  /** Local HTTP API is opt-in */
+  apiEnabled: false,
+  apiPort: 7878,
+  apiToken: "",
 };
 
 /**
@@ -191,7 +208,8 @@ export function useSettings(): UseSettingsReturn {
 
     try {
       await saveSettingsApi(newSettings);
-      setSettings(newSettings);
+      // Re-read so values filled in by the backend (e.g. a generated API token) show up
+      setSettings(await getSettings());
     } catch (err) {
       setError(err instanceof Error ? err.message : "Failed to save settings");
       throw err; // Re-throw so caller can handle (e.g., show error UI)
@@ -38,7 +38,27 @@
  */
 
 import { invoke } from "@tauri-apps/api/core";
-import type { ContributionData, Settings } from "./types";
+import type {
+  Achievement,
+  BadgeStyle,
+  ContributionData,
+  ContributionStats,
+  ExportFormat,
+  Goal,
+  GoalProgress,
+  ImportFormat,
+  ImportSummary,
+  MergePolicy,
+  Projections,
+  RangePreset,
+  RangeStats,
+  RenderTheme,
+  Settings,
+  StreakHistory,
+  StreakRecord,
+  StreakTimelinePoint,
+  UsernameValidation,
+} from "./types";
 
 // ============================================================================
 // Contribution API
@@ -150,6 +170,286 @@ export async function refreshContributions(): Promise<ContributionData> {
   return invoke<ContributionData>("refresh_contributions");
 }
 
+/**
+ * Calculates statistics as they were on a given date.
+ *
+ * Uses the cached contribution data; days after `date` are ignored and
+ * the current streak is the one that was active on that date.
+ *
+ * @param date - Reference date (YYYY-MM-DD)
+ * @returns Promise resolving to the statistics as of that date
+ * @throws Error if the date is invalid or no data has been fetched
+ *
+ * @example
+ * ```ts
+ * const stats = await getStatsAsOf("2024-03-03");
+ * console.log(`Streak on March 3rd: ${stats.currentStreak.count} days`);
+ * ```
+ */
+export async function getStatsAsOf(date: string): Promise<ContributionStats> {
+  return invoke<ContributionStats>("get_stats_as_of", { date });
+}
+
+/**
+ * Replays the current and longest streak for every day in a range.
+ *
+ * @param start - First date (YYYY-MM-DD), defaults to the first cached day
+ * @param end - Last date (YYYY-MM-DD), defaults to the last cached day
+ * @returns Promise resolving to one point per day
+ */
+export async function getStreakTimeline(
+  start?: string,
+  end?: string
+): Promise<StreakTimelinePoint[]> {
+  return invoke<StreakTimelinePoint[]>("get_streak_timeline", { start, end });
+}
+
+/**
+ * Calculates statistics for a date range or heatmap view.
+ *
+ * @param range - A heatmap preset, or an explicit start/end (YYYY-MM-DD)
+ * @returns Promise resolving to the statistics and weeks of the range
+ * @throws Error if a date is invalid, the range is empty or no data has been fetched
+ *
+ * @example
+ * ```ts
+ * const { stats } = await getRangeStats({ preset: "30d" });
+ * const march = await getRangeStats({ start: "2024-03-01", end: "2024-03-31" });
+ * ```
+ */
+export async function getRangeStats(range: {
+  preset?: RangePreset;
+  start?: string;
+  end?: string;
+}): Promise<RangeStats> {
+  return invoke<RangeStats>("get_range_stats", range);
+}
+
+/**
+ * Retrieves every streak and the gaps between them.
+ *
+ * @returns Promise resolving to the streak history
+ * @throws Error if no data has been fetched
+ */
+export async function getStreakHistory(): Promise<StreakHistory> {
+  return invoke<StreakHistory>("get_streak_history");
+}
+
+/**
+ * Retrieves the longest streaks, longest first (ties: most recent first).
+ *
+ * @param limit - Maximum number of streaks (default: 5)
+ * @returns Promise resolving to the top streaks
+ *
+ * @example
+ * ```ts
+ * const [best, second] = await getTopStreaks(2);
+ * ```
+ */
+export async function getTopStreaks(limit?: number): Promise<StreakRecord[]> {
+  return invoke<StreakRecord[]>("get_top_streaks", { limit });
+}
+
+// ============================================================================
+// Projections API
+// ============================================================================
+
+/**
+ * Projects the next streak milestone and the year-end total.
+ *
+ * Last year's total is fetched from GitHub once per user and year;
+ * offline, the "beat last year" fields are null.
+ *
+ * @returns Promise resolving to the projections
+ * @throws Error if no data has been fetched
+ */
+export async function getProjections(): Promise<Projections> {
+  return invoke<Projections>("get_projections");
+}
+
+// ============================================================================
+// Export API
+// ============================================================================
+
+/**
+ * Exports the cached days, statistics and streaks to a file.
+ *
+ * CSV files are split into metadata, days, stats and streak sections;
+ * JSON files contain a `ContributionExport`. Both start with the
+ * schema version.
+ *
+ * @param path - Destination file chosen by the user
+ * @param format - "csv" or "json"
+ * @param range - Optional first/last date (YYYY-MM-DD)
+ * @throws Error if nothing is cached, a date is invalid or the write fails
+ *
+ * @example
+ * ```ts
+ * await exportContributions("/Users/me/contributions.json", "json", { start: "2024-01-01" });
+ * ```
+ */
+export async function exportContributions(
+  path: string,
+  format: ExportFormat,
+  range: { start?: string; end?: string } = {}
+): Promise<void> {
+  return invoke("export_contributions", { path, format, ...range });
+}
+
+/**
+ * Renders the cached heatmap as a GitHub-style SVG image.
+ *
+ * @param options - Optional file path, theme (defaults to the theme
+ *   setting) and whether to add the stats footer (default: true)
+ * @returns Promise resolving to the SVG document
+ * @throws Error if no data has been fetched or the write fails
+ *
+ * @example
+ * ```ts
+ * const svg = await exportHeatmapSvg({ path: "/Users/me/heatmap.svg", theme: "dark" });
+ * ```
+ */
+export async function exportHeatmapSvg(
+  options: { path?: string; theme?: RenderTheme; includeStats?: boolean } = {}
+): Promise<string> {
+  return invoke<string>("export_heatmap_svg", options);
+}
+
+/**
+ * Renders the cached data as a PNG stats card (avatar, username,
+ * heatmap, current/longest streak and total).
+ *
+ * @param options - Optional file path, theme (defaults to the theme
+ *   setting) and whether to copy the image to the clipboard
+ * @returns Promise resolving to the PNG as a `data:` URI for previews
+ * @throws Error if no data has been fetched, or the write or copy fails
+ *
+ * @example
+ * ```ts
+ * const preview = await exportStatsCard({ copy: true });
+ * ```
+ */
+export async function exportStatsCard(
+  options: { path?: string; theme?: RenderTheme; copy?: boolean } = {}
+): Promise<string> {
+  return invoke<string>("export_stats_card", options);
+}
+
+/**
+ * Renders the README streak badge (current streak, longest streak, total).
+ *
+ * The badge is also written to `badgePath` after every refresh; failed
+ * writes are reported as "badge-error" events.
+ *
+ * @param options - Optional file path, style (default: "flat") and theme
+ *   (default: "light")
+ * @returns Promise resolving to the SVG document
+ * @throws Error if no data has been fetched or the write fails
+ *
+ * @example
+ * ```ts
+ * const svg = await exportStreakBadge({ style: "card", theme: "dark" });
+ * ```
+ */
+export async function exportStreakBadge(
+  options: { path?: string; style?: BadgeStyle; theme?: RenderTheme } = {}
+): Promise<string> {
+  return invoke<string>("export_streak_badge", options);
+}
+
+// ============================================================================
+// Import API
+// ============================================================================
+
+/**
+ * Imports day counts from a CSV file or saved `git log` output.
+ *
+ * The days are merged into the cached data (call `getCachedContributions`
+ * afterwards) and into every later fetch for the current user. Each import
+ * keeps its own policy. Days outside GitHub's one-year calendar are skipped
+ * and counted in `skippedDays`.
+ *
+ * @param path - File to read
+ * @param format - "csv" or "git-log"
+ * @param policy - How to combine counts for known days (default: "max")
+ * @returns Promise resolving to the number of days and contributions imported,
+ *   and the number of days skipped
+ * @throws Error if no username is set, nothing is cached yet, the file can't
+ *   be read or parsed, or every day is outside the calendar window
+ *
+ * @example
+ * ```ts
+ * // git log --author=me --format=%ad --date=short > ~/commits.txt
+ * const { days } = await importContributions("/Users/me/commits.txt", "git-log", "sum");
+ * ```
+ */
+export async function importContributions(
+  path: string,
+  format: ImportFormat,
+  policy?: MergePolicy
+): Promise<ImportSummary> {
+  return invoke<ImportSummary>("import_contributions", { path, format, policy });
+}
+
+// ============================================================================
+// Goals API
+// ============================================================================
+
+/**
+ * Retrieves the saved contribution goals.
+ *
+ * @returns Promise resolving to the goals (empty if none are saved)
+ */
+export async function getGoals(): Promise<Goal[]> {
+  return invoke<Goal[]>("get_goals");
+}
+
+/**
+ * Saves the contribution goals, replacing the existing ones.
+ *
+ * @param goals - The complete list of goals
+ * @throws Error if a goal is invalid (empty/duplicate id, zero target, bad weekday)
+ *
+ * @example
+ * ```ts
+ * await saveGoals([{ id: "daily", name: "Daily five", period: "day", target: 5, weekdays: [] }]);
+ * ```
+ */
+export async function saveGoals(goals: Goal[]): Promise<void> {
+  return invoke("save_goals", { goals });
+}
+
+/**
+ * Calculates the progress of every goal from the cached data.
+ *
+ * Listen for the "goal-met" event (payload: GoalProgress) to be told
+ * when a goal is reached for its current period.
+ *
+ * @returns Promise resolving to the progress per goal
+ * @throws Error if no data has been fetched
+ */
+export async function getGoalProgress(): Promise<GoalProgress[]> {
+  return invoke<GoalProgress[]>("get_goal_progress");
+}
+
+// ============================================================================
+// Achievements API
+// ============================================================================
+
+/**
+ * Gets every achievement the current user unlocked, with its unlock date.
+ *
+ * Achievements are detected after each fetch and kept per tracked user.
+ * Listen for the
+ * "achievement-unlocked" event (payload: Achievement) to be told about
+ * new ones; each is announced only once.
+ *
+ * @returns Promise resolving to the unlocked achievements
+ */
+export async function getAchievements(): Promise<Achievement[]> {
+  return invoke<Achievement[]>("get_achievements");
+}
+
 // ============================================================================
 // Settings API
 // ============================================================================
@@ -218,3 +518,29 @@ export async function getSettings(): Promise<Settings> {
 export async function saveSettings(settings: Settings): Promise<void> {
   return invoke("save_settings", { settings });
 }
+
+/**
+ * Validates a GitHub username before saving it.
+ *
+ * The backend trims whitespace, strips a leading "@", lowercases the
+ * input and checks GitHub's login rules. With `checkExists`, it also
+ * asks GitHub whether the account exists.
+ *
+ * @param username - Username as typed in the settings form
+ * @param checkExists - Whether to check that the account exists
+ * @returns Promise resolving to the validation result
+ *
+ * @example
+ * ```ts
+ * const result = await validateUsername(" @Octocat ", true);
+ * if (!result.valid || result.exists === false) {
+ *   showError(result.error);
+ * }
+ * ```
+ */
+export async function validateUsername(
+  username: string,
+  checkExists = false
+): Promise<UsernameValidation> {
+  return invoke<UsernameValidation>("validate_username", { username, checkExists });
+}
@@ -10,7 +10,8 @@
  * ContributionData
  * ├── user: UserInfo
  * │   ├── username: string
- * │   └── avatarUrl: string
+ * │   ├── avatarUrl: string
+ * │   └── displayName, bio, company, followers, ... (profile)
  * │
  * ├── weeks: ContributionWeek[]
  * │   └── days: ContributionDay[]
@@ -23,7 +24,13 @@
  * │   ├── bestDay: BestDay
  * │   ├── averagePerDay: number
  * │   ├── currentStreak: Streak
- * │   └── longestStreak: Streak
+ * │   ├── longestStreak: Streak
+ * │   ├── reportedTotal: number | null
+ * │   └── totalIsEstimate: boolean
+ * │
+ * ├── privateContributions: PrivateContributions
+ * │   ├── included: boolean
+ * │   └── count: number | null
  * │
  * └── lastUpdated: string (ISO 8601)
  * ```
@@ -185,6 +192,182 @@ export interface Streak {
   endDate: string;
 }
 
+// ============================================================================
+// Streak Timeline
+// ============================================================================
+
+/**
+ * The streak values on a single date, as if stats were calculated that day.
+ *
+ * @example
+ * ```ts
+ * const point: StreakTimelinePoint = {
+ *   date: "2024-03-03",
+ *   currentStreak: 12,
+ *   longestStreak: 42
+ * };
+ * ```
+ */
+export interface StreakTimelinePoint {
+  /** Date of this point (YYYY-MM-DD) */
+  date: string;
+
+  /** Current streak length on this date */
+  currentStreak: number;
+
+  /** Longest streak achieved up to this date */
+  longestStreak: number;
+}
+
+// ============================================================================
+// Streak History
+// ============================================================================
+
+/**
+ * A single streak from the contribution history.
+ */
+export interface StreakRecord {
+  /** Number of streak days */
+  count: number;
+
+  /** First day of the streak (YYYY-MM-DD) */
+  startDate: string;
+
+  /** Last day of the streak (YYYY-MM-DD) */
+  endDate: string;
+
+  /** Contributions made on the streak days */
+  totalContributions: number;
+}
+
+/**
+ * A break between two streaks.
+ */
+export interface StreakGap {
+  /** First day of the break (YYYY-MM-DD) */
+  startDate: string;
+
+  /** Last day of the break (YYYY-MM-DD) */
+  endDate: string;
+
+  /** Length of the break in calendar days */
+  days: number;
+}
+
+/**
+ * Every streak and break in the contribution history.
+ */
+export interface StreakHistory {
+  /** All streaks in chronological order */
+  streaks: StreakRecord[];
+
+  /** Breaks between consecutive streaks in chronological order */
+  gaps: StreakGap[];
+
+  /** The longest break between two streaks */
+  longestGap: StreakGap | null;
+
+  /** Days since the last contribution (0 = contributed today) */
+  daysSinceLastContribution: number | null;
+}
+
+// ============================================================================
+// Aggregates
+// ============================================================================
+
+/**
+ * Total contributions in a calendar month.
+ */
+export interface MonthlyTotal {
+  /** Month (YYYY-MM) */
+  month: string;
+
+  /** Sum of contributions in the month */
+  total: number;
+
+  /** Days with at least one contribution */
+  activeDays: number;
+}
+
+/**
+ * Contributions made on one day of the week.
+ */
+export interface WeekdayStats {
+  /** Day of the week (0 = Sunday … 6 = Saturday) */
+  weekday: number;
+
+  /** Sum of contributions on this weekday */
+  total: number;
+
+  /** Average contributions per occurrence of this weekday */
+  mean: number;
+}
+
+/**
+ * Total contributions in a Sunday-Saturday week.
+ */
+export interface WeeklyTotal {
+  /** First day of the week (YYYY-MM-DD) */
+  startDate: string;
+
+  /** Last day of the week (YYYY-MM-DD) */
+  endDate: string;
+
+  /** Sum of contributions in the week */
+  total: number;
+}
+
+// ============================================================================
+// Trends
+// ============================================================================
+
+/**
+ * Average contributions per day over recent windows ending "today".
+ */
+export interface RollingAverages {
+  /** Mean contributions per day over the last 7 days */
+  last7Days: number;
+
+  /** Mean contributions per day over the last 30 days */
+  last30Days: number;
+
+  /** Mean contributions per day over the last 90 days */
+  last90Days: number;
+}
+
+/**
+ * Contributions in a period compared with the period before it.
+ */
+export interface PeriodChange {
+  /** Contributions in the most recent period */
+  current: number;
+
+  /** Contributions in the period before it */
+  previous: number;
+
+  /** Change in percent (null if the previous period was empty) */
+  changePercent: number | null;
+}
+
+/**
+ * Direction of recent activity (7-day vs. 30-day average).
+ */
+export type Trend = "up" | "flat" | "down";
+
+/**
+ * Percentage of active days (0-100) in recent windows.
+ */
+export interface ActiveDayShares {
+  /** Percentage of active days in the last 30 days */
+  last30Days: number;
+
+  /** Percentage of active days in the last 90 days */
+  last90Days: number;
+
+  /** Percentage of active days in the last 365 days */
+  last365Days: number;
+}
+
 // ============================================================================
 // Contribution Statistics
 // ============================================================================
@@ -223,6 +406,81 @@ export interface ContributionStats {
 
   /** Longest streak ever achieved */
   longestStreak: Streak;
+
+  /** Current streak with rest days skipped (equals currentStreak without rest days) */
+  relaxedCurrentStreak: Streak;
+
+  /** Longest streak with rest days skipped */
+  relaxedLongestStreak: Streak;
+
+  /** Rest weekdays used for the relaxed streaks (0 = Sunday) */
+  restDays: number[];
+
+  /** Minimum contributions a day needed to count towards the streaks */
+  streakThreshold: number;
+
+  /** Ongoing run of consecutive weeks reaching weeklyTarget (count in weeks) */
+  currentWeeklyStreak: Streak;
+
+  /** Longest run of consecutive weeks reaching weeklyTarget */
+  longestWeeklyStreak: Streak;
+
+  /** Minimum contributions a week needed to count towards the weekly streaks */
+  weeklyTarget: number;
+
+  /** Ongoing run of consecutive months with activity (count in months) */
+  currentMonthlyStreak: Streak;
+
+  /** Longest run of consecutive months with activity */
+  longestMonthlyStreak: Streak;
+
+  /** Contributions per calendar month, in chronological order */
+  monthlyTotals: MonthlyTotal[];
+
+  /** Total and mean contributions per weekday, Sunday first */
+  weekdayDistribution: WeekdayStats[];
+
+  /** Contributions per Sunday-Saturday week, in chronological order */
+  weeklyTotals: WeeklyTotal[];
+
+  /** Week with the most contributions (null without any activity) */
+  bestWeek: WeeklyTotal | null;
+
+  /** Month with the most contributions (null without any activity) */
+  bestMonth: MonthlyTotal | null;
+
+  /** Last 7 days compared with the 7 days before */
+  weekOverWeek: PeriodChange;
+
+  /** Last 30 days compared with the 30 days before */
+  monthOverMonth: PeriodChange;
+
+  /** Rolling 7/30/90-day averages */
+  rollingAverages: RollingAverages;
+
+  /** Direction of recent activity */
+  trend: Trend;
+
+  /** Median contributions per day */
+  medianPerDay: number;
+
+  /** Standard deviation of the daily contributions */
+  stdDeviation: number;
+
+  /** Percentage of active days over the last 30/90/365 days */
+  activeDays: ActiveDayShares;
+
+  /** Number of days per heatmap level (index 0 = level 0 … 4 = level 4) */
+  levelHistogram: [number, number, number, number, number];
+
+  /** How regularly contributions happen, independent of volume (0-100) */
+  consistencyScore: number;
+
+  /** Total from GitHub's calendar header, if it was found */
+  reportedTotal: number | null;
+
+  /** Whether totalContributions disagrees with reportedTotal (counts were estimated) */
+  totalIsEstimate: boolean;
 }
 
 // ============================================================================
@@ -257,6 +515,75 @@ export interface UserInfo {
 
   /** URL to the user's avatar image */
   avatarUrl: string;
+
+  /** Cached avatar as a data: URI (works offline), if available */
+  avatarDataUri: string | null;
+
+  /** Display name from the public profile */
+  displayName: string | null;
+
+  /** Profile bio */
+  bio: string | null;
+
+  /** Company field from the profile */
+  company: string | null;
+
+  /** Number of followers */
+  followers: number | null;
+
+  /** Number of accounts the user follows */
+  following: number | null;
+
+  /** Account creation timestamp (ISO 8601) */
+  createdAt: string | null;
+}
+
+// ============================================================================
+// Username Validation
+// ============================================================================
+
+/**
+ * Result of validating a GitHub username.
+ *
+ * @example
+ * ```ts
+ * const result: UsernameValidation = {
+ *   username: "octocat",
+ *   valid: true,
+ *   exists: true,
+ *   error: null
+ * };
+ * ```
+ */
+export interface UsernameValidation {
+  /** Normalized username (trimmed, lowercased, without "@") */
+  username: string;
+
+  /** Whether the username follows GitHub's login rules */
+  valid: boolean;
+
+  /** Whether the account exists (null if not checked or the check failed) */
+  exists: boolean | null;
+
+  /** Description of the problem, if any */
+  error: string | null;
+}
+
+// ============================================================================
+// Private Contributions
+// ============================================================================
+
+/**
+ * Whether the calendar counts include private (anonymous) contributions.
+ *
+ * Explains why a calendar can be busier than the user's public repositories.
+ */
+export interface PrivateContributions {
+  /** Whether private contribution markers were found */
+  included: boolean;
+
+  /** Number of private contributions, if GitHub states it */
+  count: number | null;
 }
 
 // ============================================================================
@@ -303,6 +630,9 @@ export interface ContributionData {
   /** Calculated statistics for stats cards */
   stats: ContributionStats;
 
+  /** Private contribution markers detected in the calendar */
+  privateContributions: PrivateContributions;
+
   /** ISO 8601 timestamp of when data was fetched */
   lastUpdated: string;
 }
@@ -365,6 +695,350 @@ export interface Settings {
 
   /** UI theme preference */
   theme: "system" | "light" | "dark";
+
+  /** IANA timezone per tracked username; missing users follow the system zone */
+  timezones: Record<string, string>;
+
+  /** Rest weekdays that neither extend nor break a streak (0 = Sunday … 6 = Saturday) */
+  restDays: number[];
+
+  /** Minimum contributions for a day to count towards a streak (default: 1) */
+  streakThreshold: number;
+
+  /** Minimum contributions for a week to count towards a weekly streak (default: 1) */
+  weeklyTarget: number;
+
+  /** Minimum counts for heatmap levels 1 to 4 (empty = GitHub-style quartiles) */
+  levelThresholds: number[];
+
+  /** Absolute file the streak badge is written to after every refresh (empty = off) */
+  badgePath: string;
+
+  /** Layout of the streak badge */
+  badgeStyle: BadgeStyle;
+
+  /** Color theme of the streak badge */
+  badgeTheme: RenderTheme;
+
+  /** Whether the local HTTP API on 127.0.0.1 is running */
+  apiEnabled: boolean;
+
+  /** Port of the local HTTP API (default: 7878) */
+  apiPort: number;
+
+  /** Bearer token required by the local HTTP API (generated when first enabled) */
+  apiToken: string;
+}
+
+// ============================================================================
+// Range Statistics
+// ============================================================================
+
+/**
+ * The heatmap views, used as shortcuts for a stats date range.
+ *
+ * - "30d": 30 Days view
+ * - "12w": 12 Weeks view
+ * - "year": Year view (all cached days)
+ */
+export type RangePreset = "30d" | "12w" | "year";
+
+/**
+ * Statistics and heatmap weeks for a date range.
+ */
+export interface RangeStats {
+  /** First date of the range (YYYY-MM-DD) */
+  startDate: string;
+
+  /** Last date of the range (YYYY-MM-DD) */
+  endDate: string;
+
+  /** Statistics calculated from the days in the range only */
+  stats: ContributionStats;
+
+  /** Weeks of the range for heatmap rendering */
+  weeks: ContributionWeek[];
+}
+
+// ============================================================================
+// Export
+// ============================================================================
+
+/**
+ * File format for `exportContributions`.
+ */
+export type ExportFormat = "csv" | "json";
+
+/**
+ * Content of a JSON export (schema version 1).
+ *
+ * Uses dedicated export types, so it doesn't follow changes to
+ * `ContributionStats`.
+ */
+export interface ContributionExport {
+  /** Export schema version */
+  schemaVersion: number;
+
+  /** The tracked GitHub login */
+  username: string;
+
+  /** When the export was written (ISO 8601) */
+  exportedAt: string;
+
+  /** First exported date (YYYY-MM-DD) */
+  startDate: string;
+
+  /** Last exported date (YYYY-MM-DD) */
+  endDate: string;
+
+  /** Every day in the range */
+  days: ExportDay[];
+
+  /** Statistics calculated from the exported days */
+  stats: ExportStats;
+
+  /** Every streak in the range */
+  streaks: ExportStreak[];
+}
+
+/**
+ * A day in the export.
+ */
+export interface ExportDay {
+  /** Date in YYYY-MM-DD format */
+  date: string;
+
+  /** Number of contributions on this day */
+  count: number;
+
+  /** Heatmap level (0-4) */
+  level: number;
+}
+
+/**
+ * Statistics in the export. Streaks are lengths only (days, weeks or months).
+ */
+export interface ExportStats {
+  totalContributions: number;
+  bestDayDate: string;
+  bestDayCount: number;
+  averagePerDay: number;
+  medianPerDay: number;
+  stdDeviation: number;
+  currentStreak: number;
+  longestStreak: number;
+  relaxedCurrentStreak: number;
+  relaxedLongestStreak: number;
+  currentWeeklyStreak: number;
+  longestWeeklyStreak: number;
+  currentMonthlyStreak: number;
+  longestMonthlyStreak: number;
+
+  /** How regularly contributions happen (0-100) */
+  consistencyScore: number;
+
+  /** Contributions per calendar month, chronologically */
+  monthlyTotals: { month: string; total: number; activeDays: number }[];
+
+  /** Contributions per weekday, Sunday first */
+  weekdayTotals: { weekday: number; total: number; mean: number }[];
+}
+
+/**
+ * A streak in the export.
+ */
+export interface ExportStreak {
+  /** First day of the streak (YYYY-MM-DD) */
+  startDate: string;
+
+  /** Last day of the streak (YYYY-MM-DD) */
+  endDate: string;
+
+  /** Length in days */
+  count: number;
+
+  /** Contributions during the streak */
+  totalContributions: number;
+}
+
+// ============================================================================
+// Import
+// ============================================================================
+
+/**
+ * Input format for `importContributions`: `date,count` CSV (an exported
+ * CSV works too) or `git log --format=%ad --date=short` output.
+ */
+export type ImportFormat = "csv" | "git-log";
+
+/**
+ * How imported counts are combined with known counts for the same day.
+ */
+export type MergePolicy = "max" | "sum" | "replace";
+
+/**
+ * Result of `importContributions`.
+ */
+export interface ImportSummary {
+  /** Number of distinct days imported */
+  days: number;
+
+  /** Sum of the imported counts */
+  contributions: number;
+
+  /** Days before GitHub's calendar window or after today, not imported */
+  skippedDays: number;
+
+  /** First imported date (YYYY-MM-DD) */
+  startDate: string;
+
+  /** Last imported date (YYYY-MM-DD) */
+  endDate: string;
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+/**
+ * Color theme for rendered images (no "system": images use one concrete theme).
+ */
+export type RenderTheme = "light" | "dark";
+
+/**
+ * Layout of the README streak badge: shields-style with rounded or
+ * square corners, or a three-column card.
+ */
+export type BadgeStyle = "flat" | "flat-square" | "card";
+
+// ============================================================================
+// Goals
+// ============================================================================
+
+/**
+ * The period a goal's target applies to.
+ */
+export type GoalPeriod = "day" | "week" | "month";
+
+/**
+ * A personal contribution target, e.g. "150 per month".
+ */
+export interface Goal {
+  /** Unique identifier */
+  id: string;
+
+  /** Display name ("Daily five") */
+  name: string;
+
+  /** Period the target applies to */
+  period: GoalPeriod;
+
+  /** Contributions needed per period */
+  target: number;
+
+  /** Weekdays the goal applies to (0 = Sunday … 6 = Saturday, empty = all) */
+  weekdays: number[];
+}
+
+/**
+ * Progress of a goal in the current period.
+ *
+ * Also the payload of the "goal-met" event.
+ */
+export interface GoalProgress {
+  /** The goal definition */
+  goal: Goal;
+
+  /** First day of the current period (YYYY-MM-DD) */
+  periodStart: string;
+
+  /** Last day of the current period (YYYY-MM-DD) */
+  periodEnd: string;
+
+  /** Contributions so far in the current period */
+  current: number;
+
+  /** Whether the target is reached for the current period */
+  met: boolean;
+
+  /** Progress towards the target in percent (0-100) */
+  percent: number;
+
+  /** Consecutive periods the goal was met (the current period gets grace) */
+  currentHitStreak: number;
+
+  /** Most consecutive periods the goal was met */
+  longestHitStreak: number;
+}
+
+// ============================================================================
+// Achievements
+// ============================================================================
+
+/**
+ * The kind of milestone an achievement stands for.
+ */
+export type AchievementKind = "streak" | "total" | "best-day";
+
+/**
+ * An unlocked achievement.
+ *
+ * Also the payload of the "achievement-unlocked" event.
+ */
+export interface Achievement {
+  /** Stable identifier ("streak-30", "total-1000", "best-day-42") */
+  id: string;
+
+  /** The kind of milestone */
+  kind: AchievementKind;
+
+  /** Short title ("30-day streak") */
+  title: string;
+
+  /** What was achieved ("Contributed 30 days in a row") */
+  description: string;
+
+  /** The milestone value (days or contributions) */
+  value: number;
+
+  /** When the achievement was unlocked (ISO 8601) */
+  unlockedAt: string;
+}
+
+// ============================================================================
+// Projections
+// ============================================================================
+
+/**
+ * Projections based on the current streak and the recent daily rate.
+ */
+export interface Projections {
+  /** Current streak in days */
+  currentStreak: number;
+
+  /** Next streak milestone in days (null once every milestone is passed) */
+  nextStreakMilestone: number | null;
+
+  /** Date the next milestone is reached if the streak continues (YYYY-MM-DD) */
+  nextMilestoneDate: string | null;
+
+  /** Recent daily rate (30-day rolling average) */
+  dailyRate: number;
+
+  /** Contributions so far this calendar year */
+  yearToDate: number;
+
+  /** Days left in the year after today */
+  daysRemaining: number;
+
+  /** Projected total on December 31st at the recent daily rate */
+  projectedYearEndTotal: number;
+
+  /** Last calendar year's total (null if it couldn't be fetched) */
+  lastYearTotal: number | null;
+
+  /** Contributions per remaining day needed to beat last year (0 once beaten) */
+  neededPerDay: number | null;
 }
 
 // ============================================================================
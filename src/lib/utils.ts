@@ -15,6 +15,7 @@
  * │
  * ├── Display Formatting
  * │   ├── formatStreak()       - "14 days"
+ * │   ├── streakLabel()        - "Current (3+/day)"
  * │   └── getContribLevelClass() - CSS class for level
  * │
  * ├── Data Helpers
@@ -117,6 +118,25 @@ export function formatStreak(streak: Streak): string {
   return `${streak.count} days`;
 }
 
+/**
+ * Labels a streak card with the streak threshold when it isn't 1.
+ *
+ * Keeps screenshots unambiguous about what counted as a streak day.
+ *
+ * @param label - Card label ("Current", "Longest")
+ * @param threshold - Minimum contributions per streak day
+ * @returns The label, e.g. "Current (3+/day)"
+ *
+ * @example
+ * ```ts
+ * streakLabel("Current", 1); // "Current"
+ * streakLabel("Current", 3); // "Current (3+/day)"
+ * ```
+ */
+export function streakLabel(label: string, threshold: number): string {
+  return threshold > 1 ? `${label} (${threshold}+/day)` : label;
+}
+
 /**
  * Formats a timestamp as relative time.
  *